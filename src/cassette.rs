@@ -0,0 +1,189 @@
+//! Record/replay cassettes for platform and domain lookups
+//!
+//! EXPRESS CONCERNS:
+//! - Saving real responses to disk in record mode
+//! - Serving saved responses in replay mode, so checks run offline and deterministically
+//! - Stable file names for requests (method, URL and body) and domains
+//! - Wrapping a transport, so checkers record or replay without knowing it
+//!
+//! DOES NOT:
+//! - Make requests itself (the wrapped transport does)
+//! - Decide availability (replayed responses go through the usual rules)
+//! - Store request headers (they can carry API tokens) or cookie values (live sessions)
+//! - Render UI or print output
+
+use anyhow::{Context, Result};
+use futures::future::{self, BoxFuture};
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use crate::config::Confidence;
+use crate::http::{HttpRequest, HttpResponse, Transport};
+
+/// What a cassette does with lookups
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Make real requests and save every response
+    Record,
+    /// Serve saved responses, never touch the network
+    Replay,
+}
+
+/// A directory of recorded lookups
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    pub dir: PathBuf,
+    pub mode: CassetteMode,
+}
+
+/// One recorded HTTP exchange
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: HttpRequest,
+    pub response: HttpResponse,
+}
+
+/// One recorded domain answer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DomainInteraction {
+    pub domain: String,
+    pub available: Option<bool>,
+    pub confidence: Confidence,
+    pub rule: String,
+}
+
+impl Cassette {
+    pub fn new(dir: impl Into<PathBuf>, mode: CassetteMode) -> Self {
+        Self { dir: dir.into(), mode }
+    }
+
+    /// Put the cassette in front of a transport
+    /// Replay mode never calls it; record mode saves everything it answers
+    pub fn wrap<T: Transport>(self, inner: T) -> CassetteTransport<T> {
+        CassetteTransport { cassette: self, inner }
+    }
+
+    /// Saved response for a request
+    pub fn replay(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let path = self.request_path(request);
+        let interaction: Interaction = read_json(&path)
+            .with_context(|| format!("No recorded response for {} {}", request.method, request.url))?;
+        Ok(interaction.response)
+    }
+
+    /// Save a response for a request, cookie values scrubbed
+    pub fn record(&self, request: &HttpRequest, response: &HttpResponse) -> Result<()> {
+        let interaction = Interaction { request: request.clone(), response: scrub_cookies(response) };
+        write_json(&self.request_path(request), &interaction)
+    }
+
+    /// Saved answer for a domain
+    pub fn replay_domain(&self, domain: &str) -> Result<DomainInteraction> {
+        read_json(&self.domain_path(domain))
+            .with_context(|| format!("No recorded answer for {}", domain))
+    }
+
+    /// Save the answer for a domain
+    pub fn record_domain(&self, answer: &DomainInteraction) -> Result<()> {
+        write_json(&self.domain_path(&answer.domain), answer)
+    }
+
+    /// `<dir>/<host>-<hash of method, url and body>.json`
+    pub fn request_path(&self, request: &HttpRequest) -> PathBuf {
+        let host = reqwest::Url::parse(&request.url).ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_else(|| "request".to_string());
+        let key = format!("{} {}\n{}", request.method.to_uppercase(), request.url, request.body.as_deref().unwrap_or(""));
+        self.dir.join(format!("{}-{:016x}.json", file_stem(&host), fnv1a(key.as_bytes())))
+    }
+
+    /// `<dir>/domain-<name>.json`
+    pub fn domain_path(&self, domain: &str) -> PathBuf {
+        self.dir.join(format!("domain-{}.json", file_stem(&domain.to_lowercase())))
+    }
+}
+
+/// A transport behind a cassette
+pub struct CassetteTransport<T> {
+    cassette: Cassette,
+    inner: T,
+}
+
+impl<T: Transport> Transport for CassetteTransport<T> {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+        match self.cassette.mode {
+            CassetteMode::Replay => future::ready(self.cassette.replay(request)).boxed(),
+            CassetteMode::Record => async move {
+                let response = self.inner.send(request).await?;
+                self.cassette.record(request, &response)?;
+                Ok(response)
+            }
+            .boxed(),
+        }
+    }
+
+    /// A replay gives the same answer every time
+    fn can_retry(&self) -> bool {
+        self.cassette.mode == CassetteMode::Record
+    }
+
+    /// Replayed checks never touch DNS; recorded hosts are taken to resolve
+    fn resolves<'a>(&'a self, host: &'a str, proxy: Option<&'a str>) -> BoxFuture<'a, bool> {
+        match self.cassette.mode {
+            CassetteMode::Replay => future::ready(true).boxed(),
+            CassetteMode::Record => self.inner.resolves(host, proxy),
+        }
+    }
+}
+
+/// Value recorded in place of every cookie value
+pub const SCRUBBED: &str = "scrubbed";
+
+/// A response with its Set-Cookie and Cookie values replaced; names and attributes stay,
+/// so replays still exercise the cookie jar
+fn scrub_cookies(response: &HttpResponse) -> HttpResponse {
+    let scrub_pair = |pair: &str| match pair.split_once('=') {
+        Some((name, _)) => format!("{}={}", name, SCRUBBED),
+        None => pair.to_string(),
+    };
+    let mut response = response.clone();
+    for (name, value) in response.headers.iter_mut() {
+        if name.eq_ignore_ascii_case("set-cookie") {
+            // Only the first pair is the cookie; the rest are attributes
+            *value = match value.split_once(';') {
+                Some((pair, attributes)) => format!("{};{}", scrub_pair(pair), attributes),
+                None => scrub_pair(value),
+            };
+        } else if name.eq_ignore_ascii_case("cookie") {
+            *value = value.split("; ").map(scrub_pair).collect::<Vec<_>>().join("; ");
+        }
+    }
+    response
+}
+
+/// FNV-1a, stable across Rust releases (unlike the std hasher)
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+}
+
+fn file_stem(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '.') { c } else { '_' })
+        .collect()
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T> {
+    let source = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read cassette {}", path.display()))?;
+    serde_json::from_str(&source)
+        .with_context(|| format!("Invalid cassette {}", path.display()))
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create cassette directory {}", dir.display()))?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(value)?)
+        .with_context(|| format!("Failed to write cassette {}", path.display()))
+}
@@ -1,37 +1,538 @@
-/// Configuration constants and platform definitions
-/// 
-/// EXPRESS CONCERNS:
-/// - Social media platform definitions (name, URL templates)
-/// - TLD preset configurations (startup, enterprise, country)
-/// - Static configuration data only
-/// 
-/// DOES NOT:
-/// - Perform any business logic
-/// - Make HTTP requests
-/// - Render UI
-/// - Validate data (only provides configuration)
-
-#[derive(Debug)]
-pub struct SocialPlatform {
-    pub name: &'static str,
-    pub url_template: &'static str,
-}
-
-pub const SOCIAL_PLATFORMS: &[SocialPlatform] = &[
-    SocialPlatform {
-        name: "youtube",
-        url_template: "https://www.youtube.com/@{}",
-    },
-    SocialPlatform {
-        name: "instagram",
-        url_template: "https://www.instagram.com/{}",
-    },
-    SocialPlatform {
-        name: "tiktok",
-        url_template: "https://www.tiktok.com/@{}",
-    },
+//! Configuration constants and platform definitions
+//! 
+//! EXPRESS CONCERNS:
+//! - Platform definitions (URL templates, detection rules, groups)
+//! - Loading platform definitions from bundled and user TOML files
+//! - Expanding multi-instance platforms (fediverse servers)
+//! - Rebasing platform URLs onto other hosts (mock servers) and pinning proxies
+//! - TLD preset configurations (startup, enterprise, country)
+//! - Social/gaming platform presets (social, gaming, all)
+//! - Distribution channel presets (cli, desktop, all)
+//! - Bundled data tables (keyword-aware TLD suggestions, header sets)
+//! 
+//! DOES NOT:
+//! - Perform any business logic
+//! - Make HTTP requests
+//! - Render UI
+//! - Validate data (only provides configuration)
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use crate::utils::UsernameRules;
+
+/// Bundled platform definitions, one TOML document per group
+pub const BUNDLED_PLATFORMS: &[&str] = &[
+    include_str!("../platforms/social.toml"),
+    include_str!("../platforms/code.toml"),
+    include_str!("../platforms/registries.toml"),
+    include_str!("../platforms/channels.toml"),
+    include_str!("../platforms/gaming.toml"),
+    include_str!("../platforms/creators.toml"),
 ];
 
+/// A platform definition: how to ask about a username and how to read the answer
+#[derive(Debug, Clone, Deserialize)]
+pub struct SocialPlatform {
+    pub name: String,
+    /// Target group the platform is checked and rendered with (e.g. "social", "code")
+    #[serde(default = "default_group")]
+    pub group: String,
+    /// Primary lookup
+    #[serde(flatten)]
+    pub probe: Probe,
+    /// Further lookups, consulted in order while a lookup says "next"
+    #[serde(default)]
+    pub fallbacks: Vec<Probe>,
+    /// Independent lookups (oEmbed, mobile site, API) that vote alongside the primary lookup
+    #[serde(default)]
+    pub signals: Vec<Probe>,
+    /// How the signals' votes become the verdict (only used with `signals`)
+    #[serde(default)]
+    pub vote: Vote,
+    /// Username format rules checked before any request
+    #[serde(default)]
+    pub validation: Option<UsernameRules>,
+    /// Names the platform reserves for itself (compared case-insensitively)
+    #[serde(default)]
+    pub reserved: Vec<String>,
+    /// Optional API token that raises rate limits
+    #[serde(default)]
+    pub auth: Option<TokenAuth>,
+    /// How the platform canonicalizes names before lookup (e.g. "pep503")
+    #[serde(default)]
+    pub normalize: Option<String>,
+    /// Notes shown with the result (e.g. typo-squat protection)
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// Hosts the platform is checked on, one result per host (e.g. fediverse servers)
+    #[serde(default)]
+    pub instances: Vec<String>,
+    /// When and how often a failed or throttled lookup is repeated
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// Request rate and circuit breaker for the platform's host
+    #[serde(default)]
+    pub rate_limit: RateLimit,
+    /// Proxy URL the platform always uses instead of the pool ("direct" for none)
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// Where the definition came from (e.g. "sherlock"); None for curated definitions
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+impl SocialPlatform {
+    /// A definition with default request settings and no rules
+    pub fn new(name: &str, url_template: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            group: default_group(),
+            probe: Probe::new(url_template),
+            fallbacks: Vec::new(),
+            signals: Vec::new(),
+            vote: Vote::default(),
+            validation: None,
+            reserved: Vec::new(),
+            auth: None,
+            normalize: None,
+            notes: None,
+            instances: Vec::new(),
+            retry: RetryPolicy::default(),
+            rate_limit: RateLimit::default(),
+            proxy: None,
+            source: None,
+        }
+    }
+
+    /// Every lookup: the primary one, its fallbacks, then the signals
+    pub fn lookups(&self) -> impl Iterator<Item = &Probe> {
+        std::iter::once(&self.probe).chain(&self.fallbacks).chain(&self.signals)
+    }
+
+    pub fn lookups_mut(&mut self) -> impl Iterator<Item = &mut Probe> {
+        std::iter::once(&mut self.probe).chain(&mut self.fallbacks).chain(&mut self.signals)
+    }
+}
+
+/// How a platform's signals settle on a verdict
+/// Conclusive answers vote "available" or "exists"; blocked, rate-limited and unknown ones abstain
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Vote {
+    /// The side with more votes wins; a tie is unknown
+    #[default]
+    Majority,
+    /// Every voting signal must agree, else unknown
+    Unanimous,
+    /// The primary lookup decides (the others only lower confidence by disagreeing)
+    Primary,
+}
+
+/// A single lookup request and how to read its response
+#[derive(Debug, Clone, Deserialize)]
+pub struct Probe {
+    /// Lookup URL, `{}` is replaced with the username
+    pub url_template: String,
+    /// Name the lookup votes under on platforms with several signals (e.g. "oembed")
+    #[serde(default)]
+    pub signal: Option<String>,
+    /// Where the username goes in the URL (path or subdomain)
+    #[serde(default)]
+    pub addressing: Addressing,
+    /// Verdict when a subdomain doesn't resolve in DNS (subdomain addressing only)
+    #[serde(default = "default_unresolved")]
+    pub unresolved: Verdict,
+    #[serde(default = "default_method")]
+    pub method: String,
+    /// Named header set from the header profile (e.g. "browser"), under `headers`
+    #[serde(default)]
+    pub header_set: Option<String>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Request body template for POST/PUT, `{}` is replaced with the username
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Follow redirects (off when a redirect itself is the signal)
+    #[serde(default = "default_follow_redirects")]
+    pub follow_redirects: bool,
+    /// Redirects through a URL containing one of these land on a wall (consent or login page),
+    /// not on the profile: the answer is blocked, whatever the status
+    #[serde(default)]
+    pub wall_redirects: Vec<String>,
+    /// Status code ("404") or class ("2xx") -> verdict
+    #[serde(default)]
+    pub status: BTreeMap<String, Verdict>,
+    /// Ordered body rules, first match decides
+    #[serde(default)]
+    pub rules: Vec<DetectionRule>,
+    /// Ids of <script> elements holding the page's JSON; JSON checks read the first one found
+    /// (the whole body when there are none)
+    #[serde(default)]
+    pub embedded_json: Vec<String>,
+    /// Verdict when the body is inspected and no rule matches
+    #[serde(default)]
+    pub fallback: Verdict,
+    /// How far the fallback verdict can be trusted (default low)
+    #[serde(default)]
+    pub fallback_confidence: Confidence,
+}
+
+impl Probe {
+    /// A GET lookup with default settings and no rules
+    pub fn new(url_template: &str) -> Self {
+        Self {
+            url_template: url_template.to_string(),
+            signal: None,
+            addressing: Addressing::default(),
+            unresolved: default_unresolved(),
+            method: default_method(),
+            header_set: None,
+            headers: BTreeMap::new(),
+            body: None,
+            follow_redirects: default_follow_redirects(),
+            wall_redirects: Vec::new(),
+            status: BTreeMap::new(),
+            rules: Vec::new(),
+            embedded_json: Vec::new(),
+            fallback: Verdict::default(),
+            fallback_confidence: Confidence::Low,
+        }
+    }
+}
+
+/// How a lookup URL addresses the username
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Addressing {
+    /// Username in the path or query (`example.com/{}`), escaped for URLs
+    #[default]
+    Path,
+    /// Username as a DNS label (`{}.example.com`), resolved before the HTTP request
+    Subdomain,
+}
+
+/// Token read from an environment variable and sent as a header when set
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenAuth {
+    /// Environment variable holding the token (e.g. GITHUB_TOKEN)
+    pub env: String,
+    #[serde(default = "default_auth_header")]
+    pub header: String,
+    /// Header value, `{token}` is replaced with the token
+    #[serde(default = "default_auth_value")]
+    pub value: String,
+}
+
+/// Retries with exponential backoff, for a platform or the domain lookups
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// Attempts in total, the first one included (1 = never retry)
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for each further one (jittered)
+    pub base_delay_ms: u64,
+    /// Longest delay between attempts; a longer Retry-After gives up instead
+    pub max_delay_ms: u64,
+    /// Status codes ("429") or classes ("5xx") worth another attempt; transport errors always are
+    pub retry_on: Vec<String>,
+    /// Wait as long as the Retry-After header asks, instead of the backoff delay
+    pub retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 500,
+            max_delay_ms: 8_000,
+            retry_on: vec!["429".to_string(), "5xx".to_string()],
+            retry_after: true,
+        }
+    }
+}
+
+/// Token bucket and circuit breaker settings, shared by everything with the same key
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RateLimit {
+    /// Requests per second once the burst is spent (unset = unlimited)
+    pub per_second: Option<f64>,
+    /// Requests that may go out back to back
+    pub burst: u32,
+    /// Bucket and breaker name (default: the host of the lookup URL)
+    pub key: Option<String>,
+    /// Blocked or rate-limited answers in a row before the rest are skipped (0 = never)
+    pub trip_after: u32,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self { per_second: None, burst: 1, key: None, trip_after: 3 }
+    }
+}
+
+/// What a status code or rule says about a username
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Available,
+    Taken,
+    /// The account exists but is suspended or banned (the name stays taken)
+    Suspended,
+    /// The platform keeps the name for itself
+    Reserved,
+    /// The platform itself rejects the name (e.g. inappropriate)
+    Invalid,
+    /// A bot check or login wall answered instead of the profile
+    Blocked,
+    RateLimited,
+    #[default]
+    Unknown,
+    /// Read the body and let the rules decide
+    Inspect,
+    /// Not conclusive, ask the next lookup
+    Next,
+}
+
+/// How far a verdict can be trusted
+/// High: an API field or a status the platform documents; low: a guess
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    #[default]
+    Low,
+    Medium,
+    High,
+}
+
+impl std::str::FromStr for Confidence {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "low" => Ok(Confidence::Low),
+            "medium" => Ok(Confidence::Medium),
+            "high" => Ok(Confidence::High),
+            _ => Err(format!("unknown confidence {} (expected low, medium or high)", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for Confidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Confidence::Low => write!(f, "low"),
+            Confidence::Medium => write!(f, "medium"),
+            Confidence::High => write!(f, "high"),
+        }
+    }
+}
+
+/// A named body rule
+#[derive(Debug, Clone, Deserialize)]
+pub struct DetectionRule {
+    pub name: String,
+    /// Matches when any of these markers is in the body
+    #[serde(default)]
+    pub any: Vec<String>,
+    /// Matches when all of these markers are in the body
+    #[serde(default)]
+    pub all: Vec<String>,
+    /// JSON pointer check against the body
+    #[serde(default)]
+    pub json: Option<JsonCheck>,
+    pub verdict: Verdict,
+    /// Detail shown next to the status (e.g. "organization")
+    /// `{value}` is replaced with the value at the JSON check's pointer
+    #[serde(default)]
+    pub label: Option<String>,
+    /// How far a match can be trusted (default high for JSON checks, medium for markers)
+    #[serde(default)]
+    pub confidence: Option<Confidence>,
+}
+
+impl DetectionRule {
+    /// Declared confidence, or the default for the kind of rule
+    pub fn confidence(&self) -> Confidence {
+        self.confidence.unwrap_or(if self.json.is_some() { Confidence::High } else { Confidence::Medium })
+    }
+}
+
+/// JSON pointer (RFC 6901) check, e.g. `/user/type` equals "Organization"
+#[derive(Debug, Clone, Deserialize)]
+pub struct JsonCheck {
+    pub pointer: String,
+    /// Value at the pointer must equal this (strings compare case-insensitively,
+    /// `{}` is replaced with the username)
+    #[serde(default)]
+    pub equals: Option<serde_json::Value>,
+    /// Value at the pointer must (or must not) exist
+    #[serde(default)]
+    pub exists: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct PlatformFile {
+    #[serde(default)]
+    platforms: Vec<SocialPlatform>,
+}
+
+fn default_method() -> String {
+    "GET".to_string()
+}
+
+fn default_follow_redirects() -> bool {
+    true
+}
+
+fn default_unresolved() -> Verdict {
+    Verdict::Available
+}
+
+fn default_group() -> String {
+    "social".to_string()
+}
+
+fn default_auth_header() -> String {
+    "Authorization".to_string()
+}
+
+fn default_auth_value() -> String {
+    "Bearer {token}".to_string()
+}
+
+/// Parse platform definitions from TOML
+pub fn parse_platforms(source: &str) -> Result<Vec<SocialPlatform>> {
+    let file: PlatformFile = toml::from_str(source).context("Failed to parse platform definitions")?;
+    Ok(file.platforms)
+}
+
+/// Bundled platform definitions
+pub fn bundled_platforms() -> Vec<SocialPlatform> {
+    BUNDLED_PLATFORMS.iter()
+        .flat_map(|source| parse_platforms(source).expect("bundled platform definitions are valid"))
+        .collect()
+}
+
+/// Load bundled platforms, then apply user definition files or directories of *.toml files
+/// A user definition with the same name replaces the bundled one, new names are appended
+pub fn load_platforms(extra: &[impl AsRef<Path>]) -> Result<Vec<SocialPlatform>> {
+    let mut platforms = bundled_platforms();
+
+    for path in extra {
+        for file in definition_files(path.as_ref())? {
+            let source = std::fs::read_to_string(&file)
+                .with_context(|| format!("Failed to read platform definitions {}", file.display()))?;
+            let defs = parse_platforms(&source)
+                .with_context(|| format!("Invalid platform definitions in {}", file.display()))?;
+            for def in defs {
+                match platforms.iter_mut().find(|p| p.name == def.name) {
+                    Some(existing) => *existing = def,
+                    None => platforms.push(def),
+                }
+            }
+        }
+    }
+
+    Ok(platforms)
+}
+
+/// Replace each multi-instance platform with one definition per instance
+/// `{instance}` in URLs and headers becomes the host; a non-empty `instances` overrides every list
+pub fn expand_instances(platforms: Vec<SocialPlatform>, instances: &[String]) -> Vec<SocialPlatform> {
+    let mut expanded = Vec::new();
+
+    for platform in platforms {
+        if platform.instances.is_empty() {
+            expanded.push(platform);
+            continue;
+        }
+
+        let hosts = if instances.is_empty() { platform.instances.clone() } else { instances.to_vec() };
+        for host in hosts {
+            let mut def = platform.clone();
+            def.name = format!("{}:{}", platform.name, host);
+            def.instances = Vec::new();
+            for probe in def.lookups_mut() {
+                probe.url_template = probe.url_template.replace("{instance}", &host);
+                for value in probe.headers.values_mut() {
+                    *value = value.replace("{instance}", &host);
+                }
+            }
+            expanded.push(def);
+        }
+    }
+
+    expanded
+}
+
+/// Point platforms at other hosts, e.g. a local mock server
+/// Keys are platform names; a base name ("mastodon") covers all of its instances
+pub fn apply_base_urls(platforms: &mut [SocialPlatform], base_urls: &BTreeMap<String, String>) {
+    for platform in platforms.iter_mut() {
+        let Some(base) = assigned(base_urls, &platform.name) else {
+            continue;
+        };
+        for probe in platform.lookups_mut() {
+            probe.url_template = rebase_url_template(&probe.url_template, base);
+        }
+    }
+}
+
+/// Pin platforms to proxies ("direct" for none), keyed like `apply_base_urls`
+pub fn apply_proxies(platforms: &mut [SocialPlatform], proxies: &BTreeMap<String, String>) {
+    for platform in platforms.iter_mut() {
+        if let Some(proxy) = assigned(proxies, &platform.name) {
+            platform.proxy = Some(proxy.clone());
+        }
+    }
+}
+
+/// Value for a platform by full name ("mastodon:fosstodon.org") or base name ("mastodon")
+fn assigned<'a>(values: &'a BTreeMap<String, String>, name: &str) -> Option<&'a String> {
+    let base_name = name.split(':').next().unwrap_or(name);
+    values.get(name).or_else(|| values.get(base_name))
+}
+
+/// Replace the scheme and host of a URL template with `base` ("http://127.0.0.1:8080")
+/// A leading `{}.` label (subdomain addressing) is kept in front of the new host
+pub fn rebase_url_template(template: &str, base: &str) -> String {
+    let Some((_, rest)) = template.split_once("://") else {
+        return template.to_string();
+    };
+    let path_start = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (host, path) = rest.split_at(path_start);
+    let base = base.trim_end_matches('/');
+
+    match (host.starts_with("{}."), base.split_once("://")) {
+        (true, Some((scheme, base_host))) => format!("{}://{{}}.{}{}", scheme, base_host, path),
+        _ => format!("{}{}", base, path),
+    }
+}
+
+/// Host of a platform's primary lookup ("{}.substack.com" counts as substack.com)
+pub fn platform_host(platform: &SocialPlatform) -> Option<String> {
+    let url = platform.probe.url_template.replacen("{}.", "", 1);
+    reqwest::Url::parse(&url).ok()?.host_str().map(str::to_string)
+}
+
+/// A single file, or the sorted *.toml files in a directory
+fn definition_files(path: &Path) -> Result<Vec<std::path::PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut files = std::fs::read_dir(path)
+        .with_context(|| format!("Failed to read platform directory {}", path.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "toml"))
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
 /// Get TLD list based on preset name
 pub fn get_preset_tlds(preset: &str) -> Vec<String> {
     match preset.to_lowercase().as_str() {
@@ -51,3 +552,88 @@ pub fn get_preset_tlds(preset: &str) -> Vec<String> {
     .collect()
 }
 
+
+/// Bundled header sets and user-agent pool
+pub const BUNDLED_HEADERS: &str = include_str!("../data/headers.toml");
+
+/// Bundled keyword/suffix/market table for TLD suggestions
+pub const BUNDLED_TLD_SUGGESTIONS: &str = include_str!("../data/tld_suggestions.toml");
+
+/// Bundled public suffixes cookies can't be set for
+pub const BUNDLED_PUBLIC_SUFFIXES: &str = include_str!("../data/public_suffixes.toml");
+
+/// Get social and gaming platform names based on preset name
+/// Anything that isn't a preset is read as a comma-separated list of platform names
+pub fn get_platform_preset(preset: &str) -> Vec<String> {
+    match preset.to_lowercase().as_str() {
+        "social" => vec![
+            "youtube", "instagram", "tiktok", "x", "threads", "bluesky", "mastodon",
+            "telegram", "snapchat", "pinterest", "reddit", "subreddit"
+        ],
+        "gaming" => vec![
+            "twitch", "kick", "steam", "roblox"
+        ],
+        "all" => vec![
+            "youtube", "instagram", "tiktok", "x", "threads", "bluesky", "mastodon",
+            "telegram", "snapchat", "pinterest", "reddit", "subreddit",
+            "twitch", "kick", "steam", "roblox"
+        ],
+        _ => return preset.split(',').map(|s| s.trim().to_lowercase()).filter(|s| !s.is_empty()).collect(),
+    }
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+/// Which platform groups a run checks, from the `--platforms`/`--channels` presets and `--skip-*` flags
+/// Groups the flags don't know (e.g. imported site databases) are always kept
+#[derive(Debug, Clone, Default)]
+pub struct PlatformSelection {
+    /// `--platforms` preset or name list; social runs all, gaming none without it
+    pub platforms: Option<String>,
+    /// `--channels` preset or name list; no channels without it
+    pub channels: Option<String>,
+    pub skip_social: bool,
+    pub skip_code: bool,
+    pub skip_creators: bool,
+    pub skip_registries: bool,
+}
+
+impl PlatformSelection {
+    /// Drop the platforms this selection leaves out
+    pub fn retain(&self, platforms: &mut Vec<SocialPlatform>) {
+        let selected = self.platforms.as_deref().map(get_platform_preset);
+        // Multi-instance platforms are selected by their base name ("mastodon:fosstodon.org")
+        let is_selected = |name: &str| selected.as_ref().map(|s| s.iter().any(|n| n == name.split(':').next().unwrap_or(name)));
+        let channels = self.channels.as_deref().map(get_channel_preset).unwrap_or_default();
+        platforms.retain(|p| match p.group.as_str() {
+            "social" => !self.skip_social && is_selected(&p.name).unwrap_or(true),
+            "gaming" => is_selected(&p.name).unwrap_or(false),
+            "code" => !self.skip_code,
+            "creators" => !self.skip_creators,
+            "registries" => !self.skip_registries,
+            "channels" => channels.contains(&p.name),
+            _ => true,
+        });
+    }
+}
+
+/// Get distribution channel names based on preset name
+/// Anything that isn't a preset is read as a comma-separated list of channel names
+pub fn get_channel_preset(preset: &str) -> Vec<String> {
+    match preset.to_lowercase().as_str() {
+        "cli" => vec![
+            "docker-hub", "docker-official", "homebrew", "aur"
+        ],
+        "desktop" => vec![
+            "flathub", "snapcraft", "homebrew-cask", "aur"
+        ],
+        "all" => vec![
+            "docker-hub", "docker-official", "homebrew", "homebrew-cask", "flathub", "snapcraft", "aur"
+        ],
+        _ => return preset.split(',').map(|s| s.trim().to_lowercase()).filter(|s| !s.is_empty()).collect(),
+    }
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
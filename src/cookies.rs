@@ -0,0 +1,325 @@
+//! Cookie jar for platform lookups
+//!
+//! EXPRESS CONCERNS:
+//! - Keeping cookies platforms set, and sending them back on later requests
+//! - User-supplied session cookies (e.g. an Instagram sessionid)
+//! - Loading and saving the jar as a Netscape cookies.txt file
+//! - Following redirects hop by hop, so every hop's cookies are sent and kept
+//! - Refusing cookies set for a public suffix (a bare TLD, co.uk, github.io)
+//!
+//! DOES NOT:
+//! - Decide availability (delegates to the social module's rules)
+//! - Send requests itself (wraps the caller's transport)
+//! - Render UI or print output
+
+use anyhow::{Context, Result};
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use reqwest::Url;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::config::BUNDLED_PUBLIC_SUFFIXES;
+use crate::http::{location, redirect_request, HttpRequest, HttpResponse, Transport, MAX_REDIRECTS};
+
+/// One cookie, as a cookies.txt line holds it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cookie {
+    /// Host the cookie belongs to, without a leading dot
+    pub domain: String,
+    /// Also sent to subdomains of `domain`
+    pub include_subdomains: bool,
+    pub path: String,
+    /// Only sent over https
+    pub secure: bool,
+    /// Unix time it expires at; 0 = never (session and user-supplied cookies)
+    pub expires: u64,
+    pub name: String,
+    pub value: String,
+}
+
+impl Cookie {
+    /// A cookie for a domain and its subdomains, e.g. ("instagram.com", "sessionid", "...")
+    pub fn new(domain: &str, name: &str, value: &str) -> Self {
+        Self {
+            domain: domain.trim_start_matches('.').to_lowercase(),
+            include_subdomains: true,
+            path: "/".to_string(),
+            secure: false,
+            expires: 0,
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// Whether the cookie goes with a request to `url` at unix time `now`
+    pub fn matches(&self, url: &Url, now: u64) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        let host = host.to_lowercase();
+        let domain_ok = host == self.domain
+            || (self.include_subdomains && host.ends_with(&format!(".{}", self.domain)));
+        let path_ok = url.path().starts_with(&self.path);
+        let scheme_ok = !self.secure || url.scheme() == "https";
+        let fresh = self.expires == 0 || self.expires > now;
+
+        domain_ok && path_ok && scheme_ok && fresh
+    }
+}
+
+/// Cookies shared by every lookup, optionally backed by a cookies.txt file
+#[derive(Debug, Default)]
+pub struct CookieJar {
+    cookies: Mutex<Vec<Cookie>>,
+    path: Option<PathBuf>,
+}
+
+impl CookieJar {
+    /// An empty jar that is never saved
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A jar backed by a cookies.txt file; a missing file starts empty
+    pub fn load(path: &Path) -> Result<Self> {
+        let cookies = match std::fs::read_to_string(path) {
+            Ok(source) => source.lines().filter_map(parse_cookies_txt_line).collect(),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => {
+                return Err(error).with_context(|| format!("Failed to read cookie jar {}", path.display()));
+            }
+        };
+        Ok(Self { cookies: Mutex::new(cookies), path: Some(path.to_path_buf()) })
+    }
+
+    /// Write the jar back to its file, dropping expired cookies
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let now = unix_now();
+        let mut out = String::from("# Netscape HTTP Cookie File\n# Written by chexx0r; session cookies are kept\n");
+        for cookie in self.cookies.lock().unwrap().iter().filter(|c| c.expires == 0 || c.expires > now) {
+            out.push_str(&format_cookies_txt_line(cookie));
+            out.push('\n');
+        }
+        std::fs::write(path, out).with_context(|| format!("Failed to write cookie jar {}", path.display()))
+    }
+
+    /// Add a cookie, replacing one with the same domain, path and name
+    pub fn insert(&self, cookie: Cookie) {
+        let mut cookies = self.cookies.lock().unwrap();
+        cookies.retain(|c| !(c.domain == cookie.domain && c.path == cookie.path && c.name == cookie.name));
+        cookies.push(cookie);
+    }
+
+    /// Every cookie in the jar
+    pub fn cookies(&self) -> Vec<Cookie> {
+        self.cookies.lock().unwrap().clone()
+    }
+
+    /// `Cookie` header value for a request, None when nothing matches
+    pub fn header_for(&self, url: &Url) -> Option<String> {
+        let now = unix_now();
+        let pairs: Vec<String> = self.cookies.lock().unwrap().iter()
+            .filter(|c| c.matches(url, now))
+            .map(|c| format!("{}={}", c.name, c.value))
+            .collect();
+        (!pairs.is_empty()).then(|| pairs.join("; "))
+    }
+
+    /// Keep the cookies a response set; an already-expired cookie deletes its namesake
+    pub fn store_response(&self, url: &Url, response: &HttpResponse) {
+        let now = unix_now();
+        for (_, value) in response.headers.iter().filter(|(name, _)| name.eq_ignore_ascii_case("set-cookie")) {
+            let Some(cookie) = parse_set_cookie(value, url, now) else {
+                continue;
+            };
+            if cookie.expires != 0 && cookie.expires <= now {
+                self.cookies.lock().unwrap()
+                    .retain(|c| !(c.domain == cookie.domain && c.path == cookie.path && c.name == cookie.name));
+            } else {
+                self.insert(cookie);
+            }
+        }
+    }
+
+    /// A transport that sends the jar's cookies and keeps the ones it gets back
+    pub fn wrap<'a>(&'a self, inner: &'a dyn Transport) -> CookieTransport<'a> {
+        CookieTransport { jar: self, inner }
+    }
+}
+
+/// A transport with a cookie jar
+pub struct CookieTransport<'a> {
+    jar: &'a CookieJar,
+    inner: &'a dyn Transport,
+}
+
+impl CookieTransport<'_> {
+    /// Send one hop with the jar's cookies for its URL, keeping the ones it sets
+    async fn send_hop(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let url = Url::parse(&request.url).with_context(|| format!("Invalid URL {}", request.url))?;
+        let mut request = request.clone();
+        if let Some(cookies) = self.jar.header_for(&url) {
+            // A lookup's own Cookie header comes first
+            match request.headers.iter_mut().find(|(name, _)| name.eq_ignore_ascii_case("cookie")) {
+                Some((_, value)) => *value = format!("{}; {}", value, cookies),
+                None => request.headers.push(("Cookie".to_string(), cookies)),
+            }
+        }
+
+        let response = self.inner.send(&request).await?;
+        self.jar.store_response(&url, &response);
+        Ok(response)
+    }
+}
+
+impl Transport for CookieTransport<'_> {
+    /// Redirects are followed here rather than by the inner transport: consent and
+    /// login chains set cookies on the way, and each hop needs its own Cookie header
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+        async move {
+            let mut current = HttpRequest { follow_redirects: false, ..request.clone() };
+            let mut redirects = Vec::new();
+
+            loop {
+                let mut response = self.send_hop(&current).await?;
+                let next = redirect_request(&current, response.status, location(&response))
+                    .filter(|_| request.follow_redirects);
+                let Some(next) = next else {
+                    response.redirects = redirects;
+                    return Ok(response);
+                };
+                if redirects.len() == MAX_REDIRECTS {
+                    anyhow::bail!("Too many redirects from {}", request.url);
+                }
+                redirects.push(next.url.clone());
+                current = next;
+            }
+        }
+        .boxed()
+    }
+
+    fn can_retry(&self) -> bool {
+        self.inner.can_retry()
+    }
+
+    fn resolves<'a>(&'a self, host: &'a str, proxy: Option<&'a str>) -> BoxFuture<'a, bool> {
+        self.inner.resolves(host, proxy)
+    }
+}
+
+/// Parse a Set-Cookie header received from `url`
+/// Domain, Path, Secure, Expires and Max-Age are kept; a Domain outside the host is refused
+pub fn parse_set_cookie(value: &str, url: &Url, now: u64) -> Option<Cookie> {
+    let host = url.host_str()?.to_lowercase();
+    let mut parts = value.split(';');
+    let (name, value) = parts.next()?.split_once('=')?;
+    let mut cookie = Cookie {
+        domain: host.clone(),
+        include_subdomains: false,
+        path: "/".to_string(),
+        secure: false,
+        expires: 0,
+        name: name.trim().to_string(),
+        value: value.trim().trim_matches('"').to_string(),
+    };
+    if cookie.name.is_empty() {
+        return None;
+    }
+
+    let mut max_age = None;
+    for attribute in parts {
+        let (key, val) = attribute.split_once('=').unwrap_or((attribute, ""));
+        let val = val.trim();
+        match key.trim().to_lowercase().as_str() {
+            "domain" if !val.is_empty() => {
+                let domain = val.trim_start_matches('.').to_lowercase();
+                if host != domain && !host.ends_with(&format!(".{}", domain)) {
+                    return None;
+                }
+                // A suffix can only name the host itself, and then the cookie stays host-only
+                if is_public_suffix(&domain) {
+                    if host != domain {
+                        return None;
+                    }
+                    continue;
+                }
+                cookie.domain = domain;
+                cookie.include_subdomains = true;
+            }
+            "path" if val.starts_with('/') => cookie.path = val.to_string(),
+            "secure" => cookie.secure = true,
+            "max-age" => max_age = val.parse::<i64>().ok(),
+            "expires" => {
+                // Cookie dates often use dashes ("Wed, 21-Oct-2026 07:28:00 GMT")
+                if let Ok(date) = httpdate::parse_http_date(&val.replace('-', " ")) {
+                    cookie.expires = date.duration_since(UNIX_EPOCH).map_or(1, |d| d.as_secs().max(1));
+                }
+            }
+            _ => {}
+        }
+    }
+    // Max-Age wins over Expires; zero or less means "delete now"
+    if let Some(seconds) = max_age {
+        cookie.expires = if seconds <= 0 { 1 } else { now + seconds as u64 };
+    }
+
+    Some(cookie)
+}
+
+#[derive(Deserialize)]
+struct PublicSuffixes {
+    suffixes: Vec<String>,
+}
+
+/// Whether a domain is shared by unrelated sites: any single label ("com"),
+/// or a bundled public suffix ("co.uk", "github.io")
+pub fn is_public_suffix(domain: &str) -> bool {
+    static SUFFIXES: OnceLock<Vec<String>> = OnceLock::new();
+    let suffixes = SUFFIXES.get_or_init(|| {
+        toml::from_str::<PublicSuffixes>(BUNDLED_PUBLIC_SUFFIXES).expect("bundled public suffixes are valid").suffixes
+    });
+
+    !domain.contains('.') || suffixes.iter().any(|suffix| suffix == domain)
+}
+
+/// Parse a cookies.txt line: domain, subdomains flag, path, secure, expiry, name, value
+/// Comments and blank lines are skipped; "#HttpOnly_" prefixed lines are cookies
+pub fn parse_cookies_txt_line(line: &str) -> Option<Cookie> {
+    let line = line.strip_prefix("#HttpOnly_").unwrap_or(line);
+    if line.trim().is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [domain, subdomains, path, secure, expires, name, value] = fields[..] else {
+        return None;
+    };
+
+    Some(Cookie {
+        domain: domain.trim_start_matches('.').to_lowercase(),
+        include_subdomains: subdomains.eq_ignore_ascii_case("true") || domain.starts_with('.'),
+        path: path.to_string(),
+        secure: secure.eq_ignore_ascii_case("true"),
+        expires: expires.parse().unwrap_or(0),
+        name: name.to_string(),
+        value: value.to_string(),
+    })
+}
+
+/// A cookie as a cookies.txt line
+pub fn format_cookies_txt_line(cookie: &Cookie) -> String {
+    let flag = |b: bool| if b { "TRUE" } else { "FALSE" };
+    let domain = if cookie.include_subdomains { format!(".{}", cookie.domain) } else { cookie.domain.clone() };
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+        domain, flag(cookie.include_subdomains), cookie.path, flag(cookie.secure), cookie.expires, cookie.name, cookie.value
+    )
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or(Duration::ZERO).as_secs()
+}
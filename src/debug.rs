@@ -0,0 +1,81 @@
+//! Diagnostics capture for platform lookups (--debug)
+//!
+//! EXPRESS CONCERNS:
+//! - Recording each request: URL, redirect chain, status, headers, body
+//! - Recording which detection rules matched each response
+//! - Writing captured responses to the debug directory
+//!
+//! DOES NOT:
+//! - Make HTTP requests (the social module fills in captures)
+//! - Decide availability (delegates to the social module's rules)
+//! - Render UI or print output (delegates to ui module)
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use crate::config::Verdict;
+
+/// One request made while checking a platform
+#[derive(Debug, Clone, Default)]
+pub struct RequestCapture {
+    pub method: String,
+    pub url: String,
+    /// URLs redirected to, in order (empty when the lookup doesn't follow redirects)
+    pub redirects: Vec<String>,
+    pub status: Option<u16>,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+    /// Every body rule with whether it matched, in evaluation order
+    pub rules: Vec<(String, bool)>,
+    /// What this response said and which rule decided it
+    pub verdict: Verdict,
+    pub decided_by: String,
+    /// Transport error, when the request failed
+    pub error: Option<String>,
+    /// File the capture was written to
+    pub saved_to: Option<PathBuf>,
+}
+
+/// Write each capture of a platform to `<dir>/<platform>-<n>.txt`
+/// The file holds the request line, redirect chain, status, headers, a blank line, then the body
+pub fn write_captures(dir: &Path, platform: &str, captures: &mut [RequestCapture]) -> Result<()> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create debug directory {}", dir.display()))?;
+
+    for (i, capture) in captures.iter_mut().enumerate() {
+        let path = dir.join(format!("{}-{}.txt", file_stem(platform), i + 1));
+        std::fs::write(&path, render_capture(capture))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        capture.saved_to = Some(path);
+    }
+
+    Ok(())
+}
+
+/// Plain-text form of a capture
+pub fn render_capture(capture: &RequestCapture) -> String {
+    let mut out = format!("{} {}\n", capture.method, capture.url);
+    for url in &capture.redirects {
+        out.push_str(&format!("-> {}\n", url));
+    }
+    if let Some(error) = &capture.error {
+        out.push_str(&format!("error: {}\n", error));
+    }
+    if let Some(status) = capture.status {
+        out.push_str(&format!("status: {}\n", status));
+    }
+    for (name, value) in &capture.headers {
+        out.push_str(&format!("{}: {}\n", name, value));
+    }
+    out.push('\n');
+    if let Some(body) = &capture.body {
+        out.push_str(body);
+    }
+    out
+}
+
+/// Platform names as file names ("mastodon:fosstodon.org" -> "mastodon_fosstodon.org")
+fn file_stem(platform: &str) -> String {
+    platform.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '_' })
+        .collect()
+}
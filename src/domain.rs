@@ -1,55 +1,250 @@
-/// Domain availability checking functionality
-/// 
-/// EXPRESS CONCERNS:
-/// - Checking domain availability across multiple TLDs
-/// - Integrating with domain_check_lib
-/// - Returning structured domain results
-/// 
-/// DOES NOT:
-/// - Render UI or format output
-/// - Handle CLI arguments
-/// - Manage TLD presets (delegates to config module)
+//! Domain availability checking functionality
+//! 
+//! EXPRESS CONCERNS:
+//! - Checking domain availability across multiple TLDs
+//! - RDAP lookups through the caller's transport (servers found via IANA's bootstrap file)
+//! - Falling back to domain_check_lib's WHOIS for TLDs without an RDAP server
+//! - Returning structured domain results
+//! - Rating how far each answer can be trusted (RDAP vs WHOIS)
+//! - Recording answers to, or replaying them from, a cassette
+//! - Retrying failed lookups per the domain retry policy
+//! 
+//! DOES NOT:
+//! - Render UI or format output
+//! - Handle CLI arguments
+//! - Manage TLD presets (delegates to config module)
+//! - Proxy WHOIS fallbacks (plain TCP, outside the transport)
 
 use anyhow::Result;
-use domain_check_lib::DomainChecker;
-use crate::config::get_preset_tlds;
+use domain_check_lib::{CheckMethod, DomainChecker};
+use std::collections::HashMap;
+use crate::cassette::{Cassette, CassetteMode, DomainInteraction};
+use crate::config::{get_preset_tlds, rebase_url_template, Confidence, RetryPolicy};
+use crate::http::{HttpRequest, Transport};
+use crate::retry::{self, Attempt};
+
+/// IANA's list of RDAP servers by TLD
+pub const RDAP_BOOTSTRAP: &str = "https://data.iana.org/rdap/dns.json";
+
+/// How domain lookups are made
+#[derive(Default)]
+pub struct DomainOptions<'a> {
+    /// Record answers to, or replay them from, a cassette
+    pub cassette: Option<&'a Cassette>,
+    pub retry: RetryPolicy,
+    /// Send RDAP queries (the bootstrap file included) to this host instead,
+    /// e.g. a local mock server
+    pub rdap_base: Option<String>,
+    /// Proxy RDAP queries are pinned to ("direct" for none); None = the transport's pool
+    pub proxy: Option<String>,
+}
 
 /// Domain check result
 pub struct DomainResult {
     pub domain: String,
     pub available: Option<bool>, // Some(true) = available, Some(false) = taken, None = unknown
+    /// How far the answer can be trusted (registry RDAP beats free-text WHOIS)
+    pub confidence: Confidence,
+    /// Lookup that decided the answer ("rdap", "whois", ...)
+    pub rule: String,
+    /// Lookups repeated after errors or missing answers
+    pub retries: u32,
 }
 
-/// Check domain availability for a username across multiple TLDs
-/// Returns a vector of domain results - NO UI rendering
-pub async fn check_domains(username: &str, preset: &str, custom_tlds: Option<&str>) -> Result<Vec<DomainResult>> {
-    let tlds = if let Some(tlds_str) = custom_tlds {
+impl DomainResult {
+    fn unknown(domain: String, rule: &str) -> Self {
+        Self { domain, available: None, confidence: Confidence::Low, rule: rule.to_string(), retries: 0 }
+    }
+}
+
+/// Resolve the TLD list from custom TLDs or a preset name
+pub fn resolve_tlds(preset: &str, custom_tlds: Option<&str>) -> Vec<String> {
+    if let Some(tlds_str) = custom_tlds {
         tlds_str.split(',').map(|s| s.trim().to_string()).collect()
     } else {
         get_preset_tlds(preset)
-    };
+    }
+}
 
+/// Check domain availability for a username across multiple TLDs
+/// Returns a vector of domain results - NO UI rendering
+pub async fn check_domains(
+    username: &str,
+    preset: &str,
+    custom_tlds: Option<&str>,
+    transport: &dyn Transport,
+    options: &DomainOptions<'_>,
+) -> Result<Vec<DomainResult>> {
+    let domains = resolve_tlds(preset, custom_tlds)
+        .iter()
+        .map(|tld| format!("{}.{}", username, tld))
+        .collect::<Vec<_>>();
+
+    check_domain_names(&domains, transport, options).await
+}
+
+/// Check availability for a list of fully-qualified domain names
+/// Returns a vector of domain results - NO UI rendering
+/// RDAP goes through `transport` (proxies, CA and cassettes apply); errors and
+/// lookups without an answer are retried per the options' policy
+pub async fn check_domain_names(domains: &[String], transport: &dyn Transport, options: &DomainOptions<'_>) -> Result<Vec<DomainResult>> {
+    if let Some(cassette) = options.cassette.filter(|c| c.mode == CassetteMode::Replay) {
+        return Ok(domains.iter()
+            .map(|domain| match cassette.replay_domain(domain) {
+                Ok(answer) => DomainResult {
+                    domain: domain.clone(),
+                    available: answer.available,
+                    confidence: answer.confidence,
+                    rule: answer.rule,
+                    retries: 0,
+                },
+                Err(_) => DomainResult::unknown(domain.clone(), "not recorded"),
+            })
+            .collect());
+    }
+
+    let servers = rdap_servers(transport, options).await;
     let checker = DomainChecker::new();
     let mut results = Vec::new();
 
-    for tld in &tlds {
-        let domain = format!("{}.{}", username, tld);
-        
-        match checker.check_domain(&domain).await {
-            Ok(result) => {
-                results.push(DomainResult {
-                    domain,
+    for domain in domains {
+        let tld = domain.rsplit('.').next().unwrap_or(domain).to_lowercase();
+        let (mut result, retries) = match servers.get(&tld) {
+            Some(server) => rdap_lookup(transport, server, domain, options).await,
+            None => whois_lookup(&checker, domain, &options.retry).await,
+        };
+        result.retries = retries;
+
+        if let Some(cassette) = options.cassette {
+            cassette.record_domain(&DomainInteraction {
+                domain: result.domain.clone(),
+                available: result.available,
+                confidence: result.confidence,
+                rule: result.rule.clone(),
+            })?;
+        }
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+/// RDAP server base URL (ending in '/') by TLD, from the bootstrap file
+/// An unreachable bootstrap leaves every TLD to WHOIS
+async fn rdap_servers(transport: &dyn Transport, options: &DomainOptions<'_>) -> HashMap<String, String> {
+    let rebase = |url: &str| match &options.rdap_base {
+        Some(base) => rebase_url_template(url, base),
+        None => url.to_string(),
+    };
+    let request = HttpRequest {
+        method: "GET".to_string(),
+        url: rebase(RDAP_BOOTSTRAP),
+        follow_redirects: true,
+        proxy: options.proxy.clone(),
+        ..HttpRequest::default()
+    };
+    let (response, _) = retry::send(transport, &request, &options.retry).await;
+    let Some(bootstrap) = response.ok()
+        .filter(|r| r.status == 200)
+        .and_then(|r| serde_json::from_str::<serde_json::Value>(&r.body).ok()) else {
+        return HashMap::new();
+    };
+
+    parse_rdap_bootstrap(&bootstrap).into_iter()
+        .map(|(tld, server)| (tld, rebase(&server)))
+        .collect()
+}
+
+/// TLD to RDAP server pairs from a bootstrap file's `services`
+/// ([[tlds], [urls]] entries; the https URL is preferred)
+pub fn parse_rdap_bootstrap(bootstrap: &serde_json::Value) -> HashMap<String, String> {
+    let mut servers = HashMap::new();
+    let services = bootstrap.get("services").and_then(|s| s.as_array()).map(Vec::as_slice).unwrap_or_default();
+
+    for service in services {
+        let (Some(tlds), Some(urls)) = (
+            service.get(0).and_then(|t| t.as_array()),
+            service.get(1).and_then(|u| u.as_array()),
+        ) else {
+            continue;
+        };
+        let urls: Vec<&str> = urls.iter().filter_map(|u| u.as_str()).collect();
+        let Some(url) = urls.iter().find(|u| u.starts_with("https://")).or(urls.first()) else {
+            continue;
+        };
+        let server = if url.ends_with('/') { url.to_string() } else { format!("{}/", url) };
+        for tld in tlds.iter().filter_map(|t| t.as_str()) {
+            servers.insert(tld.to_lowercase(), server.clone());
+        }
+    }
+
+    servers
+}
+
+/// Ask the TLD's RDAP server: 404 is an unregistered domain, 200 a registered one
+async fn rdap_lookup(transport: &dyn Transport, server: &str, domain: &str, options: &DomainOptions<'_>) -> (DomainResult, u32) {
+    let request = HttpRequest {
+        method: "GET".to_string(),
+        url: format!("{}domain/{}", server, domain),
+        headers: vec![("Accept".to_string(), "application/rdap+json".to_string())],
+        follow_redirects: true,
+        proxy: options.proxy.clone(),
+        ..HttpRequest::default()
+    };
+    let (response, retries) = retry::send(transport, &request, &options.retry).await;
+    let available = match response.as_ref().map(|r| r.status) {
+        Ok(200) => Some(false),
+        Ok(404) => Some(true),
+        Ok(_) => return (DomainResult::unknown(domain.to_string(), "no answer"), retries),
+        Err(_) => return (DomainResult::unknown(domain.to_string(), "error"), retries),
+    };
+
+    let result = DomainResult {
+        domain: domain.to_string(),
+        available,
+        confidence: Confidence::High,
+        rule: "rdap".to_string(),
+        retries: 0,
+    };
+    (result, retries)
+}
+
+/// TLDs without an RDAP server go to domain_check_lib, whose WHOIS speaks
+/// plain TCP and so bypasses the transport (no proxy, no cassette of the exchange)
+async fn whois_lookup(checker: &DomainChecker, domain: &str, policy: &RetryPolicy) -> (DomainResult, u32) {
+    retry::run(policy, || async {
+        match checker.check_domain(domain).await {
+            Ok(result) if result.available.is_some() => {
+                let (rule, confidence) = method_confidence(result.method_used);
+                Attempt::Done(DomainResult {
+                    domain: domain.to_string(),
                     available: result.available,
-                });
-            }
-            Err(_) => {
-                results.push(DomainResult {
-                    domain,
-                    available: None,
-                });
+                    confidence,
+                    rule: rule.to_string(),
+                    retries: 0,
+                })
             }
+            Ok(_) => Attempt::Retry(DomainResult::unknown(domain.to_string(), "no answer"), None),
+            Err(_) => Attempt::Retry(DomainResult::unknown(domain.to_string(), "error"), None),
         }
+    })
+    .await
+}
+
+/// Confidence of an answer by the lookup that produced it
+/// RDAP is structured registry data; WHOIS is parsed free text
+fn method_confidence(method: CheckMethod) -> (&'static str, Confidence) {
+    match method {
+        CheckMethod::Rdap => ("rdap", Confidence::High),
+        CheckMethod::Bootstrap => ("rdap bootstrap", Confidence::High),
+        CheckMethod::Whois => ("whois", Confidence::Medium),
+        _ => ("unknown method", Confidence::Low),
     }
+}
 
-    Ok(results)
+/// Demote results decided with less than `min` confidence to unknown
+pub fn apply_min_confidence(results: &mut [DomainResult], min: Confidence) {
+    for result in results.iter_mut().filter(|r| r.confidence < min) {
+        result.available = None;
+    }
 }
@@ -0,0 +1,93 @@
+//! Request headers for platform lookups
+//!
+//! EXPRESS CONCERNS:
+//! - Named header sets platforms refer to (`header_set = "browser"`)
+//! - The rotating user-agent pool
+//! - Loading bundled and user header files
+//!
+//! DOES NOT:
+//! - Send requests (the social module builds them, transports send them)
+//! - Store cookies (delegates to cookies module)
+//! - Render UI or print output
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use crate::config::BUNDLED_HEADERS;
+
+/// Header sets and user agents
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HeaderProfile {
+    /// Picked from at random for requests without their own User-Agent
+    #[serde(default)]
+    pub user_agents: Vec<String>,
+    /// Set name -> header name -> value
+    #[serde(default)]
+    pub sets: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl HeaderProfile {
+    /// Parse a header profile from TOML
+    pub fn from_toml(source: &str) -> Result<Self> {
+        toml::from_str(source).context("Failed to parse header profile")
+    }
+
+    /// The bundled header sets and user-agent pool
+    pub fn bundled() -> Self {
+        Self::from_toml(BUNDLED_HEADERS).expect("bundled header profile is valid")
+    }
+
+    /// Merge another profile into this one
+    /// A non-empty user-agent list replaces the pool; sets merge by name, `other` winning
+    pub fn merge(&mut self, other: HeaderProfile) {
+        if !other.user_agents.is_empty() {
+            self.user_agents = other.user_agents;
+        }
+        for (name, headers) in other.sets {
+            self.sets.entry(name).or_default().extend(headers);
+        }
+    }
+
+    /// Headers for a lookup: the named set, then the lookup's own headers on top,
+    /// then a user agent from the pool unless one is already there
+    pub fn headers_for(&self, set: Option<&str>, own: &BTreeMap<String, String>) -> Result<Vec<(String, String)>> {
+        let mut headers: Vec<(String, String)> = match set {
+            Some(name) => self.sets.get(name)
+                .with_context(|| format!("No header set named {}", name))?
+                .iter()
+                .filter(|(header, _)| !own.keys().any(|o| o.eq_ignore_ascii_case(header)))
+                .map(|(header, value)| (header.clone(), value.clone()))
+                .collect(),
+            None => Vec::new(),
+        };
+        headers.extend(own.iter().map(|(header, value)| (header.clone(), value.clone())));
+
+        let has_agent = headers.iter().any(|(header, _)| header.eq_ignore_ascii_case("user-agent"));
+        if let Some(agent) = self.pick_user_agent().filter(|_| !has_agent) {
+            headers.push(("User-Agent".to_string(), agent.to_string()));
+        }
+        Ok(headers)
+    }
+
+    /// A random user agent from the pool
+    pub fn pick_user_agent(&self) -> Option<&str> {
+        if self.user_agents.is_empty() {
+            return None;
+        }
+        Some(&self.user_agents[rand::random_range(0..self.user_agents.len())])
+    }
+}
+
+/// Load the bundled header profile, merged with an optional user file
+pub fn load_header_profile(extra: Option<&Path>) -> Result<HeaderProfile> {
+    let mut profile = HeaderProfile::bundled();
+
+    if let Some(path) = extra {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read header profile {}", path.display()))?;
+        profile.merge(HeaderProfile::from_toml(&source)?);
+    }
+
+    Ok(profile)
+}
@@ -0,0 +1,180 @@
+//! HTTP transport for platform lookups
+//!
+//! EXPRESS CONCERNS:
+//! - Plain request/response values that platform checks, debug captures and cassettes share
+//! - The transport abstraction callers pass to the checkers (real network, cassette, mock)
+//! - Sending a request over reqwest, following redirects and reading the whole response
+//! - Resolving hosts for subdomain-addressed lookups
+//!
+//! DOES NOT:
+//! - Decide availability (delegates to the social module's rules)
+//! - Store or replay responses (delegates to cassette module)
+//! - Render UI or print output
+
+use anyhow::{Context, Result};
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use reqwest::{Client, ClientBuilder, Method, Url};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Redirects followed before a lookup gives up
+pub const MAX_REDIRECTS: usize = 5;
+
+/// Headers dropped when a redirect leaves the original host
+const SENSITIVE_HEADERS: &[&str] = &["authorization", "cookie", "proxy-authorization"];
+
+/// A lookup request, fully resolved (username and token already substituted)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    /// Never written to cassettes, since they can carry API tokens
+    #[serde(skip)]
+    pub headers: Vec<(String, String)>,
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Follow redirects (off when a redirect itself is the signal)
+    #[serde(skip)]
+    pub follow_redirects: bool,
+    /// Proxy the platform is pinned to ("direct" for none); None = the transport's choice
+    #[serde(skip)]
+    pub proxy: Option<String>,
+}
+
+/// A response read to the end
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HttpResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// URLs redirected to, in order
+    #[serde(default)]
+    pub redirects: Vec<String>,
+    #[serde(default)]
+    pub body: String,
+}
+
+/// Where platform checks send their requests
+/// The checkers never build a client themselves; callers pass one in
+pub trait Transport: Send + Sync {
+    /// Send a request and read the full response
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse>>;
+
+    /// Whether repeating a request can get a different answer (not for replays)
+    fn can_retry(&self) -> bool {
+        true
+    }
+
+    /// Whether a host has DNS records, for a lookup pinned to `proxy` (if any)
+    fn resolves<'a>(&'a self, host: &'a str, _proxy: Option<&'a str>) -> BoxFuture<'a, bool> {
+        async move {
+            tokio::net::lookup_host((host, 443)).await.is_ok_and(|mut addrs| addrs.next().is_some())
+        }
+        .boxed()
+    }
+}
+
+/// The real network, over reqwest
+/// Redirects are followed here rather than by reqwest, so every response carries its chain
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Browser user agent, 10 second timeout
+    pub fn new() -> Result<Self> {
+        Ok(Self { client: client_builder().build()? })
+    }
+
+    /// Use a caller-built client (see `client_builder`); it should not follow redirects itself
+    pub fn with_client(client: Client) -> Self {
+        Self { client }
+    }
+
+    async fn send_following(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let mut current = request.clone();
+        let mut redirects = Vec::new();
+
+        loop {
+            let method = Method::from_bytes(current.method.to_uppercase().as_bytes())
+                .with_context(|| format!("Invalid HTTP method {}", current.method))?;
+            let url = Url::parse(&current.url).with_context(|| format!("Invalid URL {}", current.url))?;
+            let mut builder = self.client.request(method, url);
+            for (name, value) in &current.headers {
+                builder = builder.header(name, value);
+            }
+            if let Some(body) = &current.body {
+                builder = builder.body(body.clone());
+            }
+
+            let response = builder
+                .send()
+                .await
+                .context("Failed to send request")?;
+
+            let status = response.status().as_u16();
+            let location = response.headers().get(reqwest::header::LOCATION)
+                .and_then(|value| value.to_str().ok());
+
+            if let Some(next) = redirect_request(&current, status, location).filter(|_| request.follow_redirects) {
+                if redirects.len() == MAX_REDIRECTS {
+                    anyhow::bail!("Too many redirects from {}", request.url);
+                }
+                redirects.push(next.url.clone());
+                current = next;
+                continue;
+            }
+
+            let headers = response.headers().iter()
+                .map(|(name, value)| (name.to_string(), value.to_str().unwrap_or("<binary>").to_string()))
+                .collect();
+            let body = response.text().await.context("Failed to read response body")?;
+
+            return Ok(HttpResponse { status, headers, redirects, body });
+        }
+    }
+}
+
+/// The request a redirect leads to; None when the response isn't a redirect with a usable Location
+/// 303, and 301/302 after a POST, continue as a bodiless GET; credentials stay with their host
+pub fn redirect_request(request: &HttpRequest, status: u16, location: Option<&str>) -> Option<HttpRequest> {
+    if !(300..400).contains(&status) {
+        return None;
+    }
+    let url = Url::parse(&request.url).ok()?;
+    let next = url.join(location?).ok()?;
+
+    let mut redirected = request.clone();
+    if status == 303 || (matches!(status, 301 | 302) && request.method.eq_ignore_ascii_case("post")) {
+        redirected.method = "GET".to_string();
+        redirected.body = None;
+    }
+    if next.host_str() != url.host_str() {
+        redirected.headers.retain(|(name, _)| !SENSITIVE_HEADERS.iter().any(|s| name.eq_ignore_ascii_case(s)));
+    }
+    redirected.url = next.to_string();
+    Some(redirected)
+}
+
+/// A response's Location header
+pub fn location(response: &HttpResponse) -> Option<&str> {
+    response.headers.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("location"))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Client settings every lookup uses: browser user agent, 10 second timeout,
+/// redirects left to the transport
+pub fn client_builder() -> ClientBuilder {
+    Client::builder()
+        .user_agent("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36")
+        .timeout(Duration::from_secs(10))
+        .redirect(reqwest::redirect::Policy::none())
+}
+
+impl Transport for ReqwestTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+        self.send_following(request).boxed()
+    }
+}
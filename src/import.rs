@@ -0,0 +1,250 @@
+//! Importers for community username-check site databases
+//!
+//! EXPRESS CONCERNS:
+//! - Converting Sherlock `data.json` entries into platform definitions
+//! - Converting WhatsMyName `wmn-data.json` sites into platform definitions
+//! - Merging imported definitions behind the curated ones
+//!
+//! DOES NOT:
+//! - Make HTTP requests (imported definitions run through the social module)
+//! - Render UI or format output
+//! - Download site databases (users point at a local copy)
+
+use anyhow::{bail, Context, Result};
+use serde_json::{Map, Value};
+use std::path::Path;
+use crate::config::{DetectionRule, SocialPlatform, Verdict};
+use crate::utils::{Pattern, UsernameRules};
+
+/// Group imported definitions are checked and rendered with
+/// Presets and `--skip-*` flags only know curated names, so imports stay out of their groups
+pub const IMPORTED_GROUP: &str = "imported";
+
+/// Site database formats chexx0r can import
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SiteDatabase {
+    Sherlock,
+    WhatsMyName,
+}
+
+/// Options for importing site databases
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImportOptions {
+    /// Keep sites flagged as NSFW
+    pub include_nsfw: bool,
+}
+
+/// Detect the format of a site database from its JSON shape
+/// WhatsMyName has a top-level "sites" array, Sherlock maps site names to objects
+pub fn detect_format(json: &Value) -> Option<SiteDatabase> {
+    let object = json.as_object()?;
+    if object.get("sites").is_some_and(Value::is_array) {
+        Some(SiteDatabase::WhatsMyName)
+    } else if object.values().any(|v| v.get("url").is_some()) {
+        Some(SiteDatabase::Sherlock)
+    } else {
+        None
+    }
+}
+
+/// Load a Sherlock or WhatsMyName database file into platform definitions
+pub fn load_site_database(path: &Path, options: ImportOptions) -> Result<Vec<SocialPlatform>> {
+    let source = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read site database {}", path.display()))?;
+    let json: Value = serde_json::from_str(&source)
+        .with_context(|| format!("Site database {} is not valid JSON", path.display()))?;
+
+    match detect_format(&json) {
+        Some(SiteDatabase::Sherlock) => import_sherlock(&json, options),
+        Some(SiteDatabase::WhatsMyName) => import_whatsmyname(&json, options),
+        None => bail!("Unrecognized site database format in {}", path.display()),
+    }
+}
+
+/// Append imported definitions whose names aren't taken by curated ones
+/// Curated platforms always win over imported entries
+pub fn merge_imported(platforms: &mut Vec<SocialPlatform>, imported: Vec<SocialPlatform>) {
+    for platform in imported {
+        if !platforms.iter().any(|p| p.name == platform.name) {
+            platforms.push(platform);
+        }
+    }
+}
+
+/// Convert a Sherlock `data.json` document
+///
+/// Sherlock error types map to:
+/// - `status_code`: 2xx is taken, 4xx (or listed `errorCode`s) is available
+/// - `message`: any `errorMsg` in the body means available, otherwise taken
+/// - `response_url`: redirects are not followed, 2xx is taken, 3xx/4xx is available
+pub fn import_sherlock(json: &Value, options: ImportOptions) -> Result<Vec<SocialPlatform>> {
+    let sites = json.as_object().context("Sherlock database must be a JSON object")?;
+    let mut platforms = Vec::new();
+
+    for (site, entry) in sites {
+        if site.starts_with('$') {
+            continue; // "$schema"
+        }
+        let Some(url) = entry.get("urlProbe").or_else(|| entry.get("url")).and_then(Value::as_str) else {
+            continue;
+        };
+        if !options.include_nsfw && entry.get("isNSFW").and_then(Value::as_bool).unwrap_or(false) {
+            continue;
+        }
+
+        let mut platform = SocialPlatform::new(&platform_name(site), url);
+        platform.group = IMPORTED_GROUP.to_string();
+        platform.source = Some("sherlock".to_string());
+        platform.probe.fallback = Verdict::Taken;
+        if let Some(method) = entry.get("request_method").and_then(Value::as_str) {
+            platform.probe.method = method.to_string();
+        }
+        if let Some(payload) = entry.get("request_payload") {
+            platform.probe.body = Some(payload.to_string());
+        }
+        platform.probe.headers = string_map(entry.get("headers"));
+
+        let error_types = strings(entry.get("errorType"));
+        if error_types.iter().any(|t| t == "status_code") {
+            platform.probe.status.insert("2xx".to_string(), Verdict::Taken);
+            platform.probe.status.insert("4xx".to_string(), Verdict::Available);
+            for code in codes(entry.get("errorCode")) {
+                platform.probe.status.insert(code.to_string(), Verdict::Available);
+            }
+        }
+        if error_types.iter().any(|t| t == "message") {
+            for class in ["2xx", "3xx", "4xx"] {
+                platform.probe.status.insert(class.to_string(), Verdict::Inspect);
+            }
+            platform.probe.rules.push(DetectionRule {
+                name: "sherlock-error-message".to_string(),
+                any: strings(entry.get("errorMsg")),
+                all: Vec::new(),
+                json: None,
+                verdict: Verdict::Available,
+                label: None,
+                confidence: None,
+            });
+        }
+        if error_types.iter().any(|t| t == "response_url") {
+            platform.probe.follow_redirects = false;
+            platform.probe.status.insert("2xx".to_string(), Verdict::Taken);
+            platform.probe.status.insert("3xx".to_string(), Verdict::Available);
+            platform.probe.status.insert("4xx".to_string(), Verdict::Available);
+        }
+
+        // A site database's regex that doesn't compile here leaves the site unvalidated
+        // rather than dropping it
+        if let Some(pattern) = entry.get("regexCheck").and_then(Value::as_str).and_then(|p| Pattern::new(p).ok()) {
+            platform.validation = Some(UsernameRules {
+                pattern: Some(pattern),
+                ..UsernameRules::default()
+            });
+        }
+
+        platforms.push(platform);
+    }
+
+    Ok(platforms)
+}
+
+/// Convert a WhatsMyName `wmn-data.json` document
+///
+/// A site exists when the status is `e_code` and the body has `e_string`,
+/// and is missing when the status is `m_code` and the body has `m_string`.
+pub fn import_whatsmyname(json: &Value, options: ImportOptions) -> Result<Vec<SocialPlatform>> {
+    let sites = json.get("sites").and_then(Value::as_array)
+        .context("WhatsMyName database must have a \"sites\" array")?;
+    let mut platforms = Vec::new();
+
+    for site in sites {
+        let (Some(name), Some(uri)) = (site.get("name").and_then(Value::as_str), site.get("uri_check").and_then(Value::as_str)) else {
+            continue;
+        };
+        if site.get("valid").and_then(Value::as_bool) == Some(false) {
+            continue;
+        }
+        if !options.include_nsfw && site.get("cat").and_then(Value::as_str).is_some_and(|c| c.to_lowercase().contains("nsfw")) {
+            continue;
+        }
+
+        let mut platform = SocialPlatform::new(&platform_name(name), &uri.replace("{account}", "{}"));
+        platform.group = IMPORTED_GROUP.to_string();
+        platform.source = Some("whatsmyname".to_string());
+        platform.probe.headers = string_map(site.get("headers"));
+        if let Some(body) = site.get("post_body").and_then(Value::as_str) {
+            platform.probe.method = "POST".to_string();
+            platform.probe.body = Some(body.replace("{account}", "{}"));
+        }
+
+        let e_code = site.get("e_code").and_then(Value::as_u64);
+        let m_code = site.get("m_code").and_then(Value::as_u64);
+        let e_string = site.get("e_string").and_then(Value::as_str).filter(|s| !s.is_empty());
+        let m_string = site.get("m_string").and_then(Value::as_str).filter(|s| !s.is_empty());
+
+        if let Some(code) = e_code {
+            let verdict = if e_string.is_some() { Verdict::Inspect } else { Verdict::Taken };
+            platform.probe.status.insert(code.to_string(), verdict);
+        }
+        if let Some(code) = m_code.filter(|code| Some(*code) != e_code) {
+            let verdict = if m_string.is_some() { Verdict::Inspect } else { Verdict::Available };
+            platform.probe.status.insert(code.to_string(), verdict);
+        }
+        if let Some(marker) = e_string {
+            platform.probe.rules.push(marker_rule("wmn-exists", marker, Verdict::Taken));
+        }
+        if let Some(marker) = m_string {
+            platform.probe.rules.push(marker_rule("wmn-missing", marker, Verdict::Available));
+        }
+
+        platforms.push(platform);
+    }
+
+    Ok(platforms)
+}
+
+/// Lowercase, hyphenated platform name ("Hacker News" -> "hacker-news")
+fn platform_name(site: &str) -> String {
+    site.trim().to_lowercase().split_whitespace().collect::<Vec<_>>().join("-")
+}
+
+/// A rule matching a single body marker
+fn marker_rule(name: &str, marker: &str, verdict: Verdict) -> DetectionRule {
+    DetectionRule {
+        name: name.to_string(),
+        any: vec![marker.to_string()],
+        all: Vec::new(),
+        json: None,
+        verdict,
+        label: None,
+        confidence: None,
+    }
+}
+
+/// A string or array of strings
+fn strings(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::String(s)) => vec![s.clone()],
+        Some(Value::Array(items)) => items.iter().filter_map(Value::as_str).map(str::to_string).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// A number or array of numbers
+fn codes(value: Option<&Value>) -> Vec<u64> {
+    match value {
+        Some(Value::Number(n)) => n.as_u64().into_iter().collect(),
+        Some(Value::Array(items)) => items.iter().filter_map(Value::as_u64).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// A JSON object of string values
+fn string_map(value: Option<&Value>) -> std::collections::BTreeMap<String, String> {
+    value.and_then(Value::as_object)
+        .map(Map::iter)
+        .into_iter()
+        .flatten()
+        .filter_map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+        .collect()
+}
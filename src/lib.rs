@@ -1,18 +1,30 @@
-/// Library crate for chexx0r - exposes modules for testing
-/// 
-/// EXPRESS CONCERNS:
-/// - Re-exporting public APIs for external use
-/// - Making internal modules available for integration tests
-/// - Providing a clean public interface
-/// 
-/// DOES NOT:
-/// - Contain any business logic
-/// - Render UI
-/// - Perform checks directly
+//! Library crate for chexx0r - exposes modules for testing
+//! 
+//! EXPRESS CONCERNS:
+//! - Re-exporting public APIs for external use
+//! - Making internal modules available for integration tests
+//! - Serving as the module tree for the chexx0r binary
+//! - Providing a clean public interface
+//! 
+//! DOES NOT:
+//! - Contain any business logic
+//! - Render UI
+//! - Perform checks directly
 
+pub mod cassette;
 pub mod config;
+pub mod cookies;
+pub mod debug;
 pub mod domain;
+pub mod headers;
+pub mod http;
+pub mod import;
+pub mod proxy;
+pub mod registry;
+pub mod retry;
 pub mod social;
+pub mod suggest;
+pub mod throttle;
 pub mod utils;
 pub mod ui;
 
@@ -21,16 +33,94 @@ pub use utils::{
     validate_instagram_username,
     validate_youtube_username,
     validate_tiktok_username,
+    validate_github_username,
+    validate_gitlab_username,
+    validate_codeberg_username,
+    validate_bitbucket_username,
+    validate_x_username,
+    validate_threads_username,
+    validate_bluesky_username,
+    validate_mastodon_username,
+    validate_telegram_username,
+    validate_snapchat_username,
+    validate_pinterest_username,
+    validate_reddit_username,
+    validate_subreddit_name,
+    validate_twitch_username,
+    validate_kick_username,
+    validate_steam_username,
+    validate_roblox_username,
+    validate_username,
+    ValidationError,
+    Violation,
+    UsernameRules,
+    Pattern,
+};
+
+pub use config::{
+    apply_base_urls,
+    apply_proxies,
+    bundled_platforms,
+    load_platforms,
+    Confidence,
+    PlatformSelection,
+    Probe,
+    RateLimit,
+    RetryPolicy,
+    SocialPlatform,
+    Verdict,
+    Vote,
 };
 
 pub use social::{
     check_instagram_availability,
     check_tiktok_availability,
+    evaluate_body,
+    evaluate_status,
+    status_detection,
+    CheckOptions,
+    Detection,
+    SignalVote,
     SocialResult,
     SocialStatus,
 };
 
+pub use cassette::{
+    Cassette,
+    CassetteMode,
+};
+
+pub use cookies::{
+    Cookie,
+    CookieJar,
+};
+
+pub use headers::{
+    HeaderProfile,
+};
+
+pub use http::{
+    HttpRequest,
+    HttpResponse,
+    ReqwestTransport,
+    Transport,
+};
+
 pub use domain::{
+    DomainOptions,
     DomainResult,
 };
 
+pub use proxy::{
+    ProxyTransport,
+};
+
+pub use throttle::{
+    Throttle,
+};
+
+pub use suggest::{
+    suggest_domains,
+    SuggestionTable,
+};
+
@@ -1,25 +1,22 @@
-/// Main entry point for chexx0r CLI tool
-/// 
-/// EXPRESS CONCERNS:
-/// - CLI argument parsing and validation
-/// - Orchestrating domain and social media checks
-/// - Coordinating UI rendering (delegates to ui module)
-/// - Progress indication during checks
-/// 
-/// DOES NOT:
-/// - Perform domain/social checking logic (delegates to domain/social modules)
-/// - Render UI details (delegates to ui module)
-/// - Validate usernames (delegates to utils module)
-
-mod config;
-mod domain;
-mod social;
-mod utils;
-mod ui;
+//! Main entry point for chexx0r CLI tool
+//! 
+//! EXPRESS CONCERNS:
+//! - CLI argument parsing and validation
+//! - Orchestrating domain and social media checks
+//! - Coordinating UI rendering (delegates to ui module)
+//! - Progress indication during checks
+//! 
+//! DOES NOT:
+//! - Perform domain/social checking logic (delegates to domain/social modules)
+//! - Render UI details (delegates to ui module)
+//! - Validate usernames (delegates to utils module)
 
+use chexx0r::{cassette, config, cookies, domain, headers, http, import, proxy, social, suggest, ui};
 use clap::Parser;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use indicatif::{ProgressBar, ProgressStyle};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::time::Duration;
 use colored::Colorize;
 use ui::{Dividers, Colors, render_box, spinner_template, spinner_frames, add_decorative_fill, render_domain_results, render_social_results};
@@ -42,6 +39,34 @@ struct Args {
     #[arg(short, long)]
     tlds: Option<String>,
 
+    /// Suggest extra domains from keywords, domain hacks and markets
+    #[arg(long)]
+    suggest: bool,
+
+    /// Target markets for ccTLD suggestions (comma-separated, e.g., de,uk,jp)
+    #[arg(long)]
+    market: Option<String>,
+
+    /// Extra TLD suggestion table (TOML) merged into the bundled one
+    #[arg(long, value_name = "FILE")]
+    suggest_table: Option<PathBuf>,
+
+    /// Extra platform definitions (TOML file or directory), replacing bundled ones by name
+    #[arg(long, value_name = "PATH")]
+    platform_defs: Vec<PathBuf>,
+
+    /// Sherlock data.json or WhatsMyName wmn-data.json to check alongside curated platforms
+    #[arg(long, value_name = "FILE")]
+    site_db: Vec<PathBuf>,
+
+    /// Keep NSFW sites from imported site databases
+    #[arg(long)]
+    include_nsfw: bool,
+
+    /// Fediverse instances to look the handle up on (comma-separated, e.g., mastodon.social,fosstodon.org)
+    #[arg(long)]
+    instances: Option<String>,
+
     /// Skip domain checks
     #[arg(long)]
     skip_domains: bool,
@@ -50,14 +75,113 @@ struct Args {
     #[arg(long)]
     skip_social: bool,
 
-    /// Show debug output for social media checks
+    /// Skip code host checks (GitHub, GitLab, Codeberg, Bitbucket)
+    #[arg(long)]
+    skip_code: bool,
+
+    /// Skip creator platform checks (Substack, Bandcamp, Tumblr, Ko-fi, Patreon, Medium, Linktree)
+    #[arg(long)]
+    skip_creators: bool,
+
+    /// Skip package registry checks (crates.io, npm, PyPI, RubyGems, Go, Hex)
+    #[arg(long)]
+    skip_registries: bool,
+
+    /// Social and gaming platforms to check: social, gaming, all, or a list (e.g., youtube,twitch)
+    #[arg(long, value_name = "PRESET")]
+    platforms: Option<String>,
+
+    /// Distribution channels to check: cli, desktop, all, or a list (e.g., docker-hub,aur)
+    #[arg(long, value_name = "PRESET")]
+    channels: Option<String>,
+
+    /// Demote results below this confidence (low, medium, high) to UNKNOWN
+    #[arg(long, value_name = "LEVEL", default_value = "low")]
+    min_confidence: config::Confidence,
+
+    /// Save every platform request and response and print which rules matched
     #[arg(long)]
     debug: bool,
+
+    /// Directory --debug saves requests to (implies --debug) [default: chexx0r-debug]
+    #[arg(long, value_name = "DIR")]
+    debug_dir: Option<PathBuf>,
+
+    /// Save every platform response and domain answer to DIR as a cassette
+    #[arg(long, value_name = "DIR", conflicts_with = "replay")]
+    record: Option<PathBuf>,
+
+    /// Serve platform responses and domain answers from a cassette in DIR (no network)
+    #[arg(long, value_name = "DIR")]
+    replay: Option<PathBuf>,
+
+    /// Cap attempts per lookup, retries included (overrides platform definitions; 1 = never retry)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    max_attempts: Option<u32>,
+
+    /// Send a platform's lookups to another host, e.g. youtube=http://127.0.0.1:8080; rdap= covers domain lookups (repeatable)
+    #[arg(long, value_name = "NAME=URL")]
+    base_url: Vec<String>,
+
+    /// Proxy for lookups: http://, https://, socks5:// (repeat to rotate through a pool)
+    #[arg(long, value_name = "URL")]
+    proxy: Vec<String>,
+
+    /// Pin a platform to a proxy, or to none with "direct", e.g. instagram=socks5://127.0.0.1:1080; rdap= pins domain lookups (repeatable)
+    #[arg(long, value_name = "NAME=URL")]
+    platform_proxy: Vec<String>,
+
+    /// Extra root CA certificate (PEM or DER) to trust, e.g. for an inspecting office proxy
+    #[arg(long, value_name = "FILE")]
+    ca_cert: Option<PathBuf>,
+
+    /// Extra header sets and user agents (TOML), merged over the bundled ones
+    #[arg(long, value_name = "FILE")]
+    headers: Option<PathBuf>,
+
+    /// Keep cookies between runs in a cookies.txt file (created if missing)
+    #[arg(long, value_name = "FILE")]
+    cookie_jar: Option<PathBuf>,
+
+    /// Send a session cookie to a platform or domain, e.g. instagram:sessionid=... (repeatable)
+    #[arg(long, value_name = "NAME:COOKIE=VALUE")]
+    cookie: Vec<String>,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
+    let mut domain_retry = config::RetryPolicy::default();
+    if let Some(attempts) = args.max_attempts {
+        domain_retry.max_attempts = attempts;
+    }
+    let network = proxy::ProxyTransport::new(&args.proxy, args.ca_cert.as_deref())?;
+    let platform_proxies = parse_assignments(&args.platform_proxy, "--platform-proxy")?;
+    for url in platform_proxies.values().filter(|url| *url != proxy::DIRECT) {
+        proxy::check_proxy_url(url)?;
+    }
+    let header_profile = headers::load_header_profile(args.headers.as_deref())?;
+    let jar = match &args.cookie_jar {
+        Some(path) => cookies::CookieJar::load(path)?,
+        None => cookies::CookieJar::new(),
+    };
+    let cassette = match (&args.record, &args.replay) {
+        (Some(dir), _) => Some(cassette::Cassette::new(dir, cassette::CassetteMode::Record)),
+        (_, Some(dir)) => Some(cassette::Cassette::new(dir, cassette::CassetteMode::Replay)),
+        _ => None,
+    };
+    let base_urls = parse_assignments(&args.base_url, "--base-url")?;
+    let with_cookies = jar.wrap(&network);
+    let transport: Box<dyn http::Transport + '_> = match cassette.clone() {
+        Some(cassette) => Box::new(cassette.wrap(with_cookies)),
+        None => Box::new(with_cookies),
+    };
+    let domain_options = domain::DomainOptions {
+        cassette: cassette.as_ref(),
+        retry: domain_retry,
+        rdap_base: base_urls.get("rdap").cloned(),
+        proxy: platform_proxies.get("rdap").cloned(),
+    };
 
     println!();
     
@@ -94,16 +218,78 @@ async fn main() -> Result<()> {
     // Domain checks
     let domain_results = if !args.skip_domains {
         pb.set_message("scanning domains".to_string());
-        let results = domain::check_domains(&args.username, &args.preset, args.tlds.as_deref()).await?;
+        let mut results = domain::check_domains(&args.username, &args.preset, args.tlds.as_deref(), transport.as_ref(), &domain_options).await?;
+        domain::apply_min_confidence(&mut results, args.min_confidence);
+        Some(results)
+    } else {
+        None
+    };
+
+    // Suggested domain checks
+    let markets: Vec<String> = args.market.as_deref()
+        .map(|m| m.split(',').map(|s| s.trim().to_string()).collect())
+        .unwrap_or_default();
+    let wants_suggestions = args.suggest || !markets.is_empty() || args.suggest_table.is_some();
+    let suggested_results = if !args.skip_domains && wants_suggestions {
+        pb.set_message("scanning suggested domains".to_string());
+        let table = suggest::load_suggestion_table(args.suggest_table.as_deref())?;
+        let checked_tlds = domain::resolve_tlds(&args.preset, args.tlds.as_deref());
+        let domains = suggest::suggest_domains(&args.username, &table, &markets, &checked_tlds);
+        let mut results = domain::check_domain_names(&domains, transport.as_ref(), &domain_options).await?;
+        domain::apply_min_confidence(&mut results, args.min_confidence);
         Some(results)
     } else {
         None
     };
     
-    // Social media checks
-    let social_results = if !args.skip_social {
+    // Social media, creator, code host, registry and channel checks
+    let mut platforms = config::load_platforms(&args.platform_defs)?;
+    let import_options = import::ImportOptions { include_nsfw: args.include_nsfw };
+    for path in &args.site_db {
+        import::merge_imported(&mut platforms, import::load_site_database(path, import_options)?);
+    }
+    let instances: Vec<String> = args.instances.as_deref()
+        .map(|i| i.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    let mut platforms = config::expand_instances(platforms, &instances);
+    config::apply_base_urls(&mut platforms, &base_urls);
+    config::apply_proxies(&mut platforms, &platform_proxies);
+    for entry in &args.cookie {
+        jar.insert(parse_cookie(entry, &platforms)?);
+    }
+    if let Some(attempts) = args.max_attempts {
+        for platform in &mut platforms {
+            platform.retry.max_attempts = attempts;
+        }
+    }
+    let debug_dir = match (&args.debug_dir, args.debug) {
+        (Some(dir), _) => Some(dir.clone()),
+        (None, true) => Some(PathBuf::from("chexx0r-debug")),
+        (None, false) => None,
+    };
+    let selection = config::PlatformSelection {
+        platforms: args.platforms.clone(),
+        channels: args.channels.clone(),
+        skip_social: args.skip_social,
+        skip_code: args.skip_code,
+        skip_creators: args.skip_creators,
+        skip_registries: args.skip_registries,
+    };
+    selection.retain(&mut platforms);
+
+    let social_results = if !platforms.is_empty() {
         pb.set_message("scanning socials".to_string());
-        let results = social::check_social_media(&args.username, args.debug).await?;
+        if let Some(dir) = &debug_dir {
+            std::fs::create_dir_all(dir)?;
+        }
+        let options = social::CheckOptions {
+            debug_dir: debug_dir.as_deref(),
+            headers: Some(&header_profile),
+            ..Default::default()
+        };
+        let mut results = social::check_social_media(&args.username, &platforms, transport.as_ref(), options).await?;
+        jar.save()?;
+        social::apply_min_confidence(&mut results, args.min_confidence);
         Some(results)
     } else {
         None
@@ -115,12 +301,21 @@ async fn main() -> Result<()> {
     
     // Render domain results - delegate to UI module
     if let Some(results) = domain_results {
-        render_domain_results(&results);
+        render_domain_results(&results, "domains");
+    }
+
+    if let Some(results) = suggested_results {
+        if !results.is_empty() {
+            render_domain_results(&results, "suggested");
+        }
     }
     
     // Render social results - delegate to UI module
     if let Some(results) = social_results {
         render_social_results(&results);
+        if let Some(dir) = &debug_dir {
+            ui::render_debug_summary(&results, dir);
+        }
     }
 
     // Complete section
@@ -132,3 +327,33 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Parse repeated NAME=VALUE flags into a map
+fn parse_assignments(entries: &[String], flag: &str) -> Result<BTreeMap<String, String>> {
+    let mut values = BTreeMap::new();
+    for entry in entries {
+        let Some((name, value)) = entry.split_once('=') else {
+            anyhow::bail!("{} expects NAME=URL, got {}", flag, entry);
+        };
+        values.insert(name.trim().to_string(), value.trim().to_string());
+    }
+    Ok(values)
+}
+
+/// Parse a --cookie flag: NAME:COOKIE=VALUE, NAME being a platform or a domain
+/// A platform's cookie goes to its host and subdomains ("www.instagram.com" -> instagram.com)
+fn parse_cookie(entry: &str, platforms: &[config::SocialPlatform]) -> Result<cookies::Cookie> {
+    let Some((target, (name, value))) = entry.split_once(':')
+        .and_then(|(target, cookie)| Some((target.trim(), cookie.split_once('=')?)))
+    else {
+        anyhow::bail!("--cookie expects NAME:COOKIE=VALUE, got {}", entry);
+    };
+    let domain = match platforms.iter().find(|p| p.name == target) {
+        Some(platform) => config::platform_host(platform)
+            .with_context(|| format!("--cookie: {} has no host to send cookies to", target))?,
+        None if target.contains('.') => target.to_string(),
+        None => anyhow::bail!("--cookie: unknown platform {}", target),
+    };
+    let domain = domain.strip_prefix("www.").unwrap_or(&domain);
+    Ok(cookies::Cookie::new(domain, name.trim(), value.trim()))
+}
@@ -0,0 +1,213 @@
+//! Proxies for platform and domain lookups
+//!
+//! EXPRESS CONCERNS:
+//! - Sending lookups through HTTP, HTTPS or SOCKS5 proxies
+//! - Rotating a proxy pool, benching proxies that keep failing
+//! - Pinning platforms to their own proxy (or to none)
+//! - Trusting an extra root CA (e.g. an office proxy that inspects TLS)
+//! - Keeping proxied subdomain pre-checks off local DNS
+//!
+//! DOES NOT:
+//! - Decide availability (delegates to the social module's rules)
+//! - Choose proxies for platforms (platform definitions and the CLI do)
+//! - Render UI or print output
+
+use anyhow::{Context, Result};
+use futures::future::{self, BoxFuture};
+use futures::FutureExt;
+use reqwest::{Certificate, Proxy, Url};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use crate::http::{client_builder, HttpRequest, HttpResponse, ReqwestTransport, Transport};
+
+/// Pinned proxy value that means "no proxy at all"
+pub const DIRECT: &str = "direct";
+
+/// Failures in a row before a pool proxy is benched
+const BENCH_AFTER: u32 = 3;
+
+/// How long a benched proxy sits out
+const BENCH_FOR: Duration = Duration::from_secs(60);
+
+/// A transport that sends each lookup through a proxy
+/// Pinned requests use their own proxy; the rest rotate through the pool,
+/// and with no pool they go out as the environment says (HTTPS_PROXY, ...)
+pub struct ProxyTransport {
+    pool: Vec<PoolProxy>,
+    next: AtomicUsize,
+    default: ReqwestTransport,
+    pinned: Mutex<HashMap<String, Arc<ReqwestTransport>>>,
+    root_ca: Option<Certificate>,
+}
+
+struct PoolProxy {
+    url: String,
+    transport: ReqwestTransport,
+    health: Mutex<Health>,
+}
+
+#[derive(Default)]
+struct Health {
+    failures: u32,
+    benched_until: Option<Instant>,
+}
+
+impl ProxyTransport {
+    /// A pool of proxy URLs (http://, https://, socks5://, socks5h://), optionally trusting a root CA
+    pub fn new(pool: &[String], ca_cert: Option<&Path>) -> Result<Self> {
+        let root_ca = ca_cert.map(load_root_ca).transpose()?;
+        let pool = pool.iter()
+            .map(|url| Ok(PoolProxy {
+                url: url.clone(),
+                transport: build_transport(Some(url), root_ca.as_ref())?,
+                health: Mutex::new(Health::default()),
+            }))
+            .collect::<Result<_>>()?;
+
+        let mut default = client_builder();
+        if let Some(ca) = &root_ca {
+            default = default.add_root_certificate(ca.clone());
+        }
+
+        Ok(Self {
+            pool,
+            next: AtomicUsize::new(0),
+            default: ReqwestTransport::with_client(default.build()?),
+            pinned: Mutex::new(HashMap::new()),
+            root_ca,
+        })
+    }
+
+    /// Each pool proxy (credentials hidden) with whether it is currently in rotation
+    pub fn pool_health(&self) -> Vec<(String, bool)> {
+        let now = Instant::now();
+        self.pool.iter()
+            .map(|proxy| {
+                let benched = proxy.health.lock().unwrap().benched_until.is_some_and(|until| until > now);
+                (redact(&proxy.url), !benched)
+            })
+            .collect()
+    }
+
+    /// Next pool proxy in rotation, skipping benched ones
+    /// When every proxy is benched, the one back soonest is used rather than none
+    fn pick(&self) -> Option<&PoolProxy> {
+        if self.pool.is_empty() {
+            return None;
+        }
+        let now = Instant::now();
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+
+        (0..self.pool.len())
+            .map(|i| &self.pool[(start + i) % self.pool.len()])
+            .find(|proxy| proxy.health.lock().unwrap().benched_until.is_none_or(|until| until <= now))
+            .or_else(|| self.pool.iter().min_by_key(|proxy| proxy.health.lock().unwrap().benched_until))
+    }
+
+    /// The transport for a pinned proxy, built on first use
+    fn pinned(&self, url: &str) -> Result<Arc<ReqwestTransport>> {
+        let mut pinned = self.pinned.lock().unwrap();
+        if let Some(transport) = pinned.get(url) {
+            return Ok(Arc::clone(transport));
+        }
+        let proxy = (url != DIRECT).then_some(url);
+        let transport = Arc::new(build_transport(proxy, self.root_ca.as_ref())?);
+        pinned.insert(url.to_string(), Arc::clone(&transport));
+        Ok(transport)
+    }
+
+    async fn send_via(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        if let Some(url) = &request.proxy {
+            return self.pinned(url)?.send(request).await
+                .with_context(|| format!("Via proxy {}", redact(url)));
+        }
+        let Some(proxy) = self.pick() else {
+            return self.default.send(request).await;
+        };
+
+        let response = proxy.transport.send(request).await;
+        // 407 is the proxy refusing us, not the platform answering
+        let failed = !response.as_ref().is_ok_and(|r| r.status != 407);
+        let mut health = proxy.health.lock().unwrap();
+        if failed {
+            health.failures += 1;
+            if health.failures >= BENCH_AFTER {
+                health.failures = 0;
+                health.benched_until = Some(Instant::now() + BENCH_FOR);
+            }
+        } else {
+            *health = Health::default();
+        }
+        response.with_context(|| format!("Via proxy {}", redact(&proxy.url)))
+    }
+}
+
+impl Transport for ProxyTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+        self.send_via(request).boxed()
+    }
+
+    /// Proxied lookups skip local DNS: asking it leaks the name and proxy-only networks can't answer,
+    /// so the host is taken to resolve and the proxied request decides
+    fn resolves<'a>(&'a self, host: &'a str, proxy: Option<&'a str>) -> BoxFuture<'a, bool> {
+        let proxied = match proxy {
+            Some(url) => url != DIRECT,
+            None => !self.pool.is_empty(),
+        };
+        if proxied {
+            future::ready(true).boxed()
+        } else {
+            self.default.resolves(host, proxy)
+        }
+    }
+}
+
+/// A client going through `proxy`, or through nothing at all (not even the environment's)
+fn build_transport(proxy: Option<&str>, root_ca: Option<&Certificate>) -> Result<ReqwestTransport> {
+    let mut builder = client_builder();
+    builder = match proxy {
+        Some(url) => {
+            check_proxy_url(url)?;
+            builder.proxy(Proxy::all(url).with_context(|| format!("Invalid proxy {}", redact(url)))?)
+        }
+        None => builder.no_proxy(),
+    };
+    if let Some(ca) = root_ca {
+        builder = builder.add_root_certificate(ca.clone());
+    }
+    Ok(ReqwestTransport::with_client(builder.build()?))
+}
+
+/// Only the proxy kinds reqwest can speak
+pub fn check_proxy_url(url: &str) -> Result<()> {
+    let parsed = Url::parse(url).with_context(|| format!("Invalid proxy URL {}", redact(url)))?;
+    match parsed.scheme() {
+        "http" | "https" | "socks5" | "socks5h" => Ok(()),
+        scheme => anyhow::bail!("Unsupported proxy scheme {} (use http, https, socks5 or socks5h)", scheme),
+    }
+}
+
+/// Load a root CA certificate, PEM or DER
+pub fn load_root_ca(path: &Path) -> Result<Certificate> {
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read CA certificate {}", path.display()))?;
+    Certificate::from_pem(&bytes)
+        .or_else(|_| Certificate::from_der(&bytes))
+        .with_context(|| format!("Invalid CA certificate {}", path.display()))
+}
+
+/// Proxy URL with its credentials hidden, for messages
+pub fn redact(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(mut parsed) if !parsed.username().is_empty() || parsed.password().is_some() => {
+            parsed.set_username("***").ok();
+            parsed.set_password(None).ok();
+            parsed.to_string()
+        }
+        Ok(_) => url.to_string(),
+        Err(_) => "<invalid proxy URL>".to_string(),
+    }
+}
@@ -0,0 +1,78 @@
+//! Package registry and distribution channel name normalization
+//!
+//! EXPRESS CONCERNS:
+//! - Canonicalizing names the way each registry or channel compares them
+//! - PEP 503 (PyPI), npm, crates.io and Go module path rules
+//! - Flathub reverse-DNS app IDs
+//!
+//! DOES NOT:
+//! - Make HTTP requests (registries and channels run through the social module)
+//! - Validate name format (delegates to utils via platform definitions)
+//! - Render UI or format output
+
+/// Normalize a name with a named normalizer from a platform definition
+/// Unknown normalizers leave the name unchanged
+pub fn normalize_name(normalizer: &str, name: &str) -> String {
+    match normalizer {
+        "pep503" => normalize_pep503(name),
+        "npm" => normalize_npm(name),
+        "crates" => normalize_crates(name),
+        "go" => normalize_go_module(name),
+        "flathub" => normalize_flathub_app_id(name),
+        "lowercase" => name.to_lowercase(),
+        _ => name.to_string(),
+    }
+}
+
+/// PyPI (PEP 503): lowercase, runs of `-`, `_` and `.` become a single `-`
+pub fn normalize_pep503(name: &str) -> String {
+    let mut normalized = String::new();
+    let mut in_separator = false;
+
+    for c in name.chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !in_separator {
+                normalized.push('-');
+            }
+            in_separator = true;
+        } else {
+            normalized.extend(c.to_lowercase());
+            in_separator = false;
+        }
+    }
+
+    normalized
+}
+
+/// npm: names (and @scopes) are lowercase
+pub fn normalize_npm(name: &str) -> String {
+    name.to_lowercase()
+}
+
+/// crates.io: `-` and `_` are the same crate and case is ignored
+pub fn normalize_crates(name: &str) -> String {
+    name.to_lowercase().replace('_', "-")
+}
+
+/// Go module proxy: uppercase letters are escaped as `!` + lowercase
+pub fn normalize_go_module(name: &str) -> String {
+    let mut escaped = String::new();
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            escaped.push('!');
+            escaped.push(c.to_ascii_lowercase());
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+/// Flathub: app IDs are reverse-DNS; a bare name becomes `io.github.<name>.<name>`
+pub fn normalize_flathub_app_id(name: &str) -> String {
+    if name.contains('.') {
+        name.to_string()
+    } else {
+        format!("io.github.{}.{}", name, name)
+    }
+}
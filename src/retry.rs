@@ -0,0 +1,104 @@
+//! Retries for platform and domain lookups
+//!
+//! EXPRESS CONCERNS:
+//! - Deciding whether a response or error is worth another attempt
+//! - Exponential backoff delays with jitter, honoring Retry-After
+//! - Counting the retries a lookup used
+//!
+//! DOES NOT:
+//! - Decide availability (the last answer goes through the usual rules)
+//! - Define policies (delegates to config module)
+//! - Render UI or print output
+
+use anyhow::Result;
+use std::future::Future;
+use std::time::{Duration, SystemTime};
+use crate::config::RetryPolicy;
+use crate::http::{HttpRequest, HttpResponse, Transport};
+
+/// What one attempt came back with
+pub enum Attempt<T> {
+    /// Final answer
+    Done(T),
+    /// Worth another attempt, after Retry-After when the server sent one
+    /// The value is kept as the answer if no attempts are left
+    Retry(T, Option<Duration>),
+}
+
+/// Run attempts until one is final or the policy runs out
+/// Returns the last answer with the number of retries used
+pub async fn run<T, F, Fut>(policy: &RetryPolicy, mut attempt: F) -> (T, u32)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Attempt<T>>,
+{
+    let mut retries = 0;
+
+    loop {
+        let (answer, retry_after) = match attempt().await {
+            Attempt::Done(answer) => return (answer, retries),
+            Attempt::Retry(answer, retry_after) => (answer, retry_after),
+        };
+        if retries + 1 >= policy.max_attempts {
+            return (answer, retries);
+        }
+
+        let delay = match retry_after.filter(|_| policy.retry_after) {
+            // Waiting longer than the policy allows isn't a retry, it's a stall
+            Some(wait) if wait > Duration::from_millis(policy.max_delay_ms) => return (answer, retries),
+            Some(wait) => wait,
+            None => backoff_delay(policy, retries + 1),
+        };
+        tokio::time::sleep(delay).await;
+        retries += 1;
+    }
+}
+
+/// Send a request, repeating transport errors and retryable statuses
+/// Transports that can't answer differently (replays) get a single attempt
+pub async fn send(transport: &dyn Transport, request: &HttpRequest, policy: &RetryPolicy) -> (Result<HttpResponse>, u32) {
+    if !transport.can_retry() {
+        return (transport.send(request).await, 0);
+    }
+
+    run(policy, || async {
+        match transport.send(request).await {
+            Ok(response) if should_retry(policy, response.status) => {
+                let retry_after = response.headers.iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+                    .and_then(|(_, value)| parse_retry_after(value, SystemTime::now()));
+                Attempt::Retry(Ok(response), retry_after)
+            }
+            Ok(response) => Attempt::Done(Ok(response)),
+            Err(error) => Attempt::Retry(Err(error), None),
+        }
+    })
+    .await
+}
+
+/// Whether the policy retries a status code, by exact code ("429") or class ("5xx")
+pub fn should_retry(policy: &RetryPolicy, status: u16) -> bool {
+    let code = status.to_string();
+    let class = format!("{}xx", status / 100);
+    policy.retry_on.iter().any(|s| *s == code || s.eq_ignore_ascii_case(&class))
+}
+
+/// Delay before the nth retry (1-based): base * 2^(n-1), capped, with jitter
+/// The jitter keeps half the delay and randomizes the rest, so checks don't retry in lockstep
+pub fn backoff_delay(policy: &RetryPolicy, retry: u32) -> Duration {
+    let exponential = policy.base_delay_ms.saturating_mul(1u64 << retry.saturating_sub(1).min(32));
+    let capped = exponential.min(policy.max_delay_ms);
+    let half = capped / 2;
+    Duration::from_millis(half + rand::random_range(0..=capped - half))
+}
+
+/// Parse a Retry-After value: delay seconds ("120") or an HTTP date
+/// Dates in the past mean "now"
+pub fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(now).unwrap_or(Duration::ZERO))
+}
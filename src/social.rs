@@ -1,235 +1,652 @@
-/// Social media platform availability checking functionality
-/// 
-/// EXPRESS CONCERNS:
-/// - HTTP requests to social media platforms
-/// - Parsing HTML responses to determine availability
-/// - Platform-specific detection logic (Instagram, YouTube, TikTok)
-/// - Username validation integration
-/// 
-/// DOES NOT:
-/// - Render UI or print to console (except debug mode)
-/// - Handle CLI arguments
-/// - Manage progress bars or spinners
-/// - Format output for display
+//! Social media platform availability checking functionality
+//! 
+//! EXPRESS CONCERNS:
+//! - Building the HTTP request for each platform lookup
+//! - Parsing HTML responses to determine availability
+//! - Evaluating platform detection rules (status codes, body markers, JSON checks)
+//! - Retrying failed or throttled lookups per the platform's policy
+//! - Pacing requests per host and skipping hosts that keep blocking
+//! - Resolving subdomain-addressed profiles (DNS before the HTTP probe)
+//! - Username validation integration
+//! 
+//! DOES NOT:
+//! - Render UI or print to console (debug captures are written by the debug module)
+//! - Send requests itself (delegates to the caller's transport)
+//! - Handle CLI arguments
+//! - Manage progress bars or spinners
+//! - Format output for display
+//! - Define platforms (delegates to config module)
 
 use anyhow::{Result, Context};
-use reqwest::Client;
-use futures::future::join_all;
-use std::time::Duration;
-use crate::config::SOCIAL_PLATFORMS;
-use crate::utils::{validate_instagram_username, validate_youtube_username, validate_tiktok_username};
+use futures::stream::{self, StreamExt};
+use std::path::Path;
+use crate::debug::{write_captures, RequestCapture};
+use crate::headers::HeaderProfile;
+use crate::http::{HttpRequest, Transport};
+use crate::config::{bundled_platforms, Addressing, Confidence, DetectionRule, Probe, SocialPlatform, TokenAuth, Verdict, Vote};
+use crate::registry::normalize_name;
+use crate::retry;
+use crate::throttle::{throttle_key, Throttle};
+use crate::utils::validate_username;
+
+/// Maximum platform checks in flight at once (site databases can hold hundreds)
+const MAX_CONCURRENT_CHECKS: usize = 32;
 
 /// Social media check result
 pub struct SocialResult {
     pub platform: String,
+    /// Target group the platform belongs to (e.g. "social", "code")
+    pub group: String,
     pub status: SocialStatus,
+    /// Extra detail about the status (e.g. "organization", "reserved")
+    pub detail: Option<String>,
+    /// Name actually looked up, when the platform normalized it differently
+    pub checked_as: Option<String>,
+    /// Platform notes (e.g. typo-squat protection)
+    pub notes: Option<String>,
+    /// How far the status can be trusted
+    pub confidence: Confidence,
+    /// Name of the rule that decided the status (e.g. "status 404", "title-has-username")
+    pub rule: String,
+    /// Requests repeated after errors or throttling, across all lookups
+    pub retries: u32,
+    /// Each signal's answer, on platforms with several (empty otherwise)
+    pub signals: Vec<SignalVote>,
+    /// Requests made for the check (debug mode only)
+    pub captures: Vec<RequestCapture>,
+}
+
+/// One signal's answer on a platform with several
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalVote {
+    /// Signal name (e.g. "profile", "oembed")
+    pub signal: String,
+    pub verdict: Verdict,
+    /// Rule that decided the signal's answer
+    pub rule: String,
+}
+
+impl SignalVote {
+    /// Whether the signal gave a conclusive answer (blocked, rate-limited and unknown ones abstain)
+    pub fn votes(&self) -> bool {
+        votes_on(self.verdict)
+    }
+
+    /// Whether the signal voted the other way from `status`
+    pub fn disagrees_with(&self, status: SocialStatus) -> bool {
+        self.votes() && (self.verdict == Verdict::Available) != status.is_available()
+    }
 }
 
 /// Social media availability status
+/// Only `Available` means the name can be registered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SocialStatus {
     Available,
     Taken,
+    /// Account exists but is suspended or banned
+    Suspended,
+    /// Name kept by the platform itself
+    Reserved,
+    /// Bot check or login wall, the answer says nothing about the name
+    Blocked,
+    RateLimited,
     Invalid,
     Unknown,
 }
 
+impl SocialStatus {
+    /// Map a final lookup verdict to a status (inconclusive verdicts are unknown)
+    pub fn from_verdict(verdict: Verdict) -> Self {
+        match verdict {
+            Verdict::Available => SocialStatus::Available,
+            Verdict::Taken => SocialStatus::Taken,
+            Verdict::Suspended => SocialStatus::Suspended,
+            Verdict::Reserved => SocialStatus::Reserved,
+            Verdict::Invalid => SocialStatus::Invalid,
+            Verdict::Blocked => SocialStatus::Blocked,
+            Verdict::RateLimited => SocialStatus::RateLimited,
+            Verdict::Unknown | Verdict::Inspect | Verdict::Next => SocialStatus::Unknown,
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        *self == SocialStatus::Available
+    }
+}
+
+/// Outcome of reading a response: the verdict and the rule that decided it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detection {
+    pub verdict: Verdict,
+    /// Name of the deciding rule ("status 404", a body rule's name, "fallback", ...)
+    pub rule: String,
+    pub label: Option<String>,
+    pub confidence: Confidence,
+}
+
+impl Detection {
+    pub fn new(verdict: Verdict, rule: &str, confidence: Confidence) -> Self {
+        Self { verdict, rule: rule.to_string(), label: None, confidence }
+    }
+}
+
+/// Options for a round of platform checks
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CheckOptions<'a> {
+    /// Capture every request, write it here and keep it on the result (--debug)
+    pub debug_dir: Option<&'a Path>,
+    /// Rate limits and breakers to share with other checks (default: fresh for this call)
+    pub throttle: Option<&'a Throttle>,
+    /// Header sets and user agents (default: the bundled profile)
+    pub headers: Option<&'a HeaderProfile>,
+}
+
 /// Check social media platform availability for a username
 /// Returns a vector of social results - NO UI rendering
-pub async fn check_social_media(username: &str, debug: bool) -> Result<Vec<SocialResult>> {
-    let client = Client::builder()
-        .user_agent("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36")
-        .timeout(Duration::from_secs(10))
-        .redirect(reqwest::redirect::Policy::limited(5))
-        .build()?;
-
-    let futures = SOCIAL_PLATFORMS.iter().map(|platform| {
-        let client = client.clone();
-        let username = username.to_string();
-        let platform_name = platform.name;
-        let url = platform.url_template.replace("{}", &username);
+/// Every request goes through `transport` (the network, a cassette, a mock server's client)
+pub async fn check_social_media(
+    username: &str,
+    platforms: &[SocialPlatform],
+    transport: &dyn Transport,
+    options: CheckOptions<'_>,
+) -> Result<Vec<SocialResult>> {
+    let fresh = Throttle::new();
+    let throttle = options.throttle.unwrap_or(&fresh);
+    let bundled;
+    let headers = match options.headers {
+        Some(profile) => profile,
+        None => {
+            bundled = HeaderProfile::bundled();
+            &bundled
+        }
+    };
 
+    let futures = platforms.iter().map(|platform| {
         async move {
-            // Validate username format first
-            let validation_result = match platform_name {
-                "instagram" => validate_instagram_username(&username),
-                "youtube" => validate_youtube_username(&username),
-                "tiktok" => validate_tiktok_username(&username),
-                _ => Ok(()), // Unknown platform, skip validation
+            // Normalize the way the platform compares names, then validate that form
+            let name = match &platform.normalize {
+                Some(normalizer) => normalize_name(normalizer, username),
+                None => username.to_string(),
             };
-            
-            let result = match validation_result {
-                Ok(_) => {
-                    // Username is valid, proceed with HTTP check
-                    check_social_platform(&client, &url, platform_name, debug).await
-                }
-                Err(_) => {
-                    // Username is invalid, return error immediately
-                    Err(anyhow::anyhow!("invalid username format"))
-                }
+            let invalid = platform.validation.as_ref()
+                .and_then(|rules| validate_username(&name, rules).err());
+            let reserved = platform.reserved.iter().any(|r| r.eq_ignore_ascii_case(&name));
+
+            let mut captures = Vec::new();
+            let mut retries = 0;
+            let (detection, signals) = if let Some(error) = invalid {
+                let mut detection = Detection::new(Verdict::Invalid, "validation", Confidence::High);
+                detection.label = Some(error.to_string());
+                (detection, Vec::new())
+            } else if reserved {
+                (Detection::new(Verdict::Reserved, "reserved", Confidence::High), Vec::new())
+            } else {
+                // Username is valid, proceed with HTTP check
+                let captures = options.debug_dir.is_some().then_some(&mut captures);
+                check_social_platform(transport, throttle, headers, platform, &name, &mut retries, captures).await
+                    .unwrap_or_else(|_| (Detection::new(Verdict::Unknown, "error", Confidence::Low), Vec::new()))
             };
-            
-            (platform_name, result)
+            if let Some(dir) = options.debug_dir {
+                // A capture that can't be written still shows in the summary, without a path
+                write_captures(dir, &platform.name, &mut captures).ok();
+            }
+
+            SocialResult {
+                platform: platform.name.clone(),
+                group: platform.group.clone(),
+                status: SocialStatus::from_verdict(detection.verdict),
+                detail: detection.label,
+                checked_as: (name != username).then_some(name),
+                notes: platform.notes.clone(),
+                confidence: detection.confidence,
+                rule: detection.rule,
+                retries,
+                signals,
+                captures,
+            }
         }
     });
 
-    let results = join_all(futures).await;
-    
-    let mut social_results = Vec::new();
-    for (platform_name, result) in results {
-        let status = match result {
-            Ok(is_taken) => {
-                if is_taken {
-                    SocialStatus::Taken
-                } else {
-                    SocialStatus::Available
+    Ok(stream::iter(futures).buffered(MAX_CONCURRENT_CHECKS).collect().await)
+}
+
+/// Demote results decided with less than `min` confidence to UNKNOWN
+/// Validation and reserved-name results are always high confidence
+pub fn apply_min_confidence(results: &mut [SocialResult], min: Confidence) {
+    for result in results.iter_mut().filter(|r| r.confidence < min && r.status != SocialStatus::Unknown) {
+        result.status = SocialStatus::Unknown;
+        result.detail = Some(format!("below {} confidence", min));
+    }
+}
+
+/// Check a single social media platform for username availability
+/// Runs the primary lookup, then each fallback while the answer is "next";
+/// platforms with signals run each of them too and settle the verdict by vote
+/// Retries used are added to `retries`; when `captures` is given, every request is recorded
+/// Once the host's breaker has tripped, nothing is sent and the blocking verdict is reported
+async fn check_social_platform(
+    transport: &dyn Transport,
+    throttle: &Throttle,
+    headers: &HeaderProfile,
+    platform: &SocialPlatform,
+    username: &str,
+    retries: &mut u32,
+    mut captures: Option<&mut Vec<RequestCapture>>,
+) -> Result<(Detection, Vec<SignalVote>)> {
+    let key = throttle_key(platform);
+    if let Some(detection) = circuit_open(throttle, &key) {
+        return Ok((detection, Vec::new()));
+    }
+    let throttled = throttle.wrap(transport, platform);
+    let check = PlatformCheck {
+        transport: &throttled,
+        throttle,
+        key: &key,
+        headers,
+        platform,
+        username,
+        token: platform.auth.as_ref()
+            .and_then(|auth| std::env::var(&auth.env).ok().filter(|t| !t.is_empty()).map(|t| (auth, t))),
+    };
+
+    let primary = std::iter::once(&platform.probe).chain(&platform.fallbacks);
+    if platform.signals.is_empty() {
+        return Ok((check.run(primary, retries, captures).await?, Vec::new()));
+    }
+
+    // With several signals, a lookup that fails only loses its vote
+    let mut votes = vec![(
+        platform.probe.signal.clone().unwrap_or_else(|| "primary".to_string()),
+        check.run(primary, retries, captures.as_deref_mut()).await
+            .unwrap_or_else(|_| Detection::new(Verdict::Unknown, "error", Confidence::Low)),
+    )];
+    for (i, signal) in platform.signals.iter().enumerate() {
+        let detection = match circuit_open(throttle, &key) {
+            Some(detection) => detection,
+            None => check.run(std::iter::once(signal), retries, captures.as_deref_mut()).await
+                .unwrap_or_else(|_| Detection::new(Verdict::Unknown, "error", Confidence::Low)),
+        };
+        votes.push((signal.signal.clone().unwrap_or_else(|| format!("signal {}", i + 1)), detection));
+    }
+
+    let detection = tally(platform.vote, &votes);
+    let votes = votes.into_iter()
+        .map(|(signal, detection)| SignalVote { signal, verdict: detection.verdict, rule: detection.rule })
+        .collect();
+    Ok((detection, votes))
+}
+
+/// What a check reports without asking, once the host's breaker has tripped
+fn circuit_open(throttle: &Throttle, key: &str) -> Option<Detection> {
+    let mut detection = Detection::new(throttle.tripped(key)?, "circuit open", Confidence::High);
+    detection.label = Some("skipped, host is blocking".to_string());
+    Some(detection)
+}
+
+/// Settle a platform's verdict from its signals' answers (the primary lookup's first)
+/// Disagreement lowers the winning side's confidence by one step; agreement keeps its best
+pub fn tally(vote: Vote, votes: &[(String, Detection)]) -> Detection {
+    let Some((_, primary)) = votes.first() else {
+        return Detection::new(Verdict::Unknown, "no lookup answered", Confidence::Low);
+    };
+    let voters: Vec<&Detection> = votes.iter().map(|(_, d)| d).filter(|d| votes_on(d.verdict)).collect();
+    if voters.is_empty() {
+        // Nobody could tell: the primary lookup's wall or error stands
+        return primary.clone();
+    }
+
+    let available = voters.iter().filter(|d| d.verdict == Verdict::Available).count();
+    let exists = voters.len() - available;
+    let winner_available = match vote {
+        Vote::Primary if votes_on(primary.verdict) => primary.verdict == Verdict::Available,
+        Vote::Unanimous if available > 0 && exists > 0 => return split(available, exists),
+        _ if available == exists => return split(available, exists),
+        _ => available > exists,
+    };
+
+    let side: Vec<&Detection> = voters.iter().copied()
+        .filter(|d| (d.verdict == Verdict::Available) == winner_available)
+        .collect();
+    // Within the winning side the primary lookup's reading wins, it has the most specific rules
+    let mut detection = match side.iter().find(|d| std::ptr::eq(**d, primary)) {
+        Some(primary) => (*primary).clone(),
+        None => side[0].clone(),
+    };
+    let best = side.iter().map(|d| d.confidence).max().unwrap_or_default();
+    detection.confidence = if side.len() < voters.len() {
+        match best {
+            Confidence::High => Confidence::Medium,
+            _ => Confidence::Low,
+        }
+    } else {
+        best
+    };
+    detection
+}
+
+/// Conclusive verdicts vote "available" or "exists"
+fn votes_on(verdict: Verdict) -> bool {
+    matches!(verdict, Verdict::Available | Verdict::Taken | Verdict::Suspended | Verdict::Reserved | Verdict::Invalid)
+}
+
+fn split(available: usize, exists: usize) -> Detection {
+    let mut detection = Detection::new(Verdict::Unknown, "signals split", Confidence::Low);
+    detection.label = Some(format!("{} say available, {} say taken", available, exists));
+    detection
+}
+
+/// What one platform check needs for each of its lookups
+struct PlatformCheck<'a> {
+    transport: &'a dyn Transport,
+    throttle: &'a Throttle,
+    key: &'a str,
+    headers: &'a HeaderProfile,
+    platform: &'a SocialPlatform,
+    username: &'a str,
+    token: Option<(&'a TokenAuth, String)>,
+}
+
+impl PlatformCheck<'_> {
+    /// Run lookups in order until one answers something other than "next"
+    async fn run<'p>(
+        &self,
+        probes: impl Iterator<Item = &'p Probe>,
+        retries: &mut u32,
+        mut captures: Option<&mut Vec<RequestCapture>>,
+    ) -> Result<Detection> {
+        let (transport, platform, username) = (self.transport, self.platform, self.username);
+
+        for probe in probes {
+            let url = match probe.addressing {
+                Addressing::Path => fill_url_template(&probe.url_template, username),
+                Addressing::Subdomain => {
+                    let Some(url) = fill_subdomain_template(&probe.url_template, username) else {
+                        return Ok(Detection::new(Verdict::Invalid, "dns label", Confidence::High));
+                    };
+                    match subdomain_resolves(transport, &url, platform.proxy.as_deref()).await {
+                        Some(true) => url,
+                        // The platform's own domain resolves, the name doesn't
+                        Some(false) if probe.unresolved == Verdict::Next => continue,
+                        Some(false) => return Ok(Detection::new(probe.unresolved, "dns unresolved", Confidence::High)),
+                        None => return Ok(Detection::new(Verdict::Unknown, "dns unavailable", Confidence::Low)),
+                    }
                 }
+            };
+
+            let mut request = HttpRequest {
+                method: probe.method.to_uppercase(),
+                url,
+                headers: self.headers.headers_for(probe.header_set.as_deref(), &probe.headers)?
+                    .into_iter()
+                    .map(|(name, value)| (name, value.replace("{}", username)))
+                    .collect(),
+                body: probe.body.as_ref().map(|body| body.replace("{}", username)),
+                follow_redirects: probe.follow_redirects,
+                proxy: platform.proxy.clone(),
+            };
+            if let Some((auth, token)) = &self.token {
+                request.headers.push((auth.header.clone(), auth.value.replace("{token}", token)));
             }
-            Err(e) => {
-                let error_msg = e.to_string();
-                if error_msg.contains("invalid username format") {
-                    SocialStatus::Invalid
-                } else {
-                    SocialStatus::Unknown
+
+            let mut capture = captures.is_some().then(|| RequestCapture {
+                method: request.method.clone(),
+                url: request.url.clone(),
+                ..RequestCapture::default()
+            });
+
+            let (response, used) = retry::send(transport, &request, &platform.retry).await;
+            *retries += used;
+            let response = match response {
+                Ok(response) => response,
+                Err(error) => {
+                    if let (Some(captures), Some(mut capture)) = (captures.as_deref_mut(), capture) {
+                        capture.error = Some(format!("{:#}", error));
+                        capture.decided_by = "error".to_string();
+                        captures.push(capture);
+                    }
+                    return Err(error);
                 }
+            };
+
+            let mut detection = match redirect_wall(probe, &response.redirects) {
+                Some(wall) => wall,
+                None => status_detection(probe, response.status),
+            };
+            if detection.verdict == Verdict::Inspect {
+                detection = evaluate_body(probe, username, &response.body);
             }
-        };
-        
-        social_results.push(SocialResult {
-            platform: platform_name.to_string(),
-            status,
-        });
-    }
+            self.throttle.record(self.key, &platform.rate_limit, detection.verdict);
 
-    Ok(social_results)
+            if let (Some(captures), Some(mut capture)) = (captures.as_deref_mut(), capture.take()) {
+                capture.status = Some(response.status);
+                capture.rules = trace_rules(probe, username, &response.body);
+                capture.verdict = detection.verdict;
+                capture.decided_by = detection.rule.clone();
+                capture.redirects = response.redirects;
+                capture.headers = response.headers;
+                capture.body = Some(response.body);
+                captures.push(capture);
+            }
+
+            if detection.verdict != Verdict::Next {
+                return Ok(detection);
+            }
+        }
+
+        Ok(Detection::new(Verdict::Unknown, "no lookup answered", Confidence::Low))
+    }
 }
 
-/// Check a single social media platform for username availability
-async fn check_social_platform(client: &Client, url: &str, _platform: &str, _debug: bool) -> Result<bool> {
-    let response = client
-        .get(url)
-        .send()
-        .await
-        .context("Failed to send request")?;
-
-    let status = response.status();
-    
-    // YouTube: Returns 404 for non-existent profiles, 200 for existing ones
-    if url.contains("youtube.com") {
-        if status.as_u16() == 404 {
-            return Ok(false); // Available - channel doesn't exist
-        } else if status.is_success() {
-            return Ok(true); // Taken - channel exists
-        } else {
-            // Other status codes - can't determine
-            return Err(anyhow::anyhow!("HTTP {}", status.as_u16()));
+/// Substitute the username into a URL template, escaping characters that would change the URL
+/// (e.g. the slash in an npm "@scope/name")
+pub fn fill_url_template(template: &str, username: &str) -> String {
+    let mut escaped = String::new();
+    for c in username.chars() {
+        match c {
+            '/' | '?' | '#' | '%' | ' ' | '&' => escaped.push_str(&format!("%{:02X}", c as u32)),
+            _ => escaped.push(c),
         }
     }
-    
-    // Instagram and TikTok return 200 but show specific error messages
-    if status.as_u16() == 404 {
-        return Ok(false); // Available
-    }
-    
-    if status.is_success() {
-        let body = response.text().await?;
-        let body_lower = body.to_lowercase();
-        
-        // Debug output is handled by caller (main.rs) via UI module
-        // For now, we just proceed with availability check
-        // The caller can access debug info through error context if needed
-        
-        // Check for platform-specific patterns
-        check_platform_availability(&body_lower, url)
-    } else {
-        // Other status codes - can't determine
-        Err(anyhow::anyhow!("HTTP {}", status.as_u16()))
+    template.replace("{}", &escaped)
+}
+
+/// Substitute the username into a subdomain template as a DNS label
+/// Returns None when the username can't be a DNS label (1-63 letters, digits or inner hyphens)
+pub fn fill_subdomain_template(template: &str, username: &str) -> Option<String> {
+    let label = username.to_lowercase();
+    let valid = (1..=63).contains(&label.len())
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !label.starts_with('-')
+        && !label.ends_with('-');
+
+    valid.then(|| template.replace("{}", &label))
+}
+
+/// Resolve the host of a subdomain URL
+/// Some(false) only when the host is missing but its parent domain resolves,
+/// None when DNS itself can't be trusted (offline, parent missing)
+async fn subdomain_resolves(transport: &dyn Transport, url: &str, proxy: Option<&str>) -> Option<bool> {
+    let url = reqwest::Url::parse(url).ok()?;
+    let host = url.host_str()?;
+
+    if transport.resolves(host, proxy).await {
+        return Some(true);
     }
+    let (_, parent) = host.split_once('.')?;
+    transport.resolves(parent, proxy).await.then_some(false)
+}
+
+/// Map a response status code to a verdict using the lookup's status table
+/// Exact codes ("404") win over classes ("2xx"); unlisted codes use the defaults
+pub fn evaluate_status(probe: &Probe, status: u16) -> Verdict {
+    status_detection(probe, status).verdict
 }
 
-/// Check platform-specific availability patterns in HTML body
-fn check_platform_availability(body_lower: &str, url: &str) -> Result<bool> {
-    // Instagram: Check title tag to determine if profile exists
-    if url.contains("instagram.com") {
-        return check_instagram_availability(body_lower, url);
+/// Blocked when a redirect went through one of the lookup's wall URLs (e.g. consent.youtube.com)
+pub fn redirect_wall(probe: &Probe, redirects: &[String]) -> Option<Detection> {
+    let wall = redirects.iter().find(|url| probe.wall_redirects.iter().any(|marker| url.contains(marker.as_str())))?;
+    let host = reqwest::Url::parse(wall).ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| wall.clone());
+
+    let mut detection = Detection::new(Verdict::Blocked, "wall redirect", Confidence::High);
+    detection.label = Some(format!("redirected to {}", host));
+    Some(detection)
+}
+
+/// Status table verdict with the entry that decided it
+/// Listed codes are trusted; the built-in defaults are weaker
+pub fn status_detection(probe: &Probe, status: u16) -> Detection {
+    let code = status.to_string();
+    let class = format!("{}xx", status / 100);
+
+    for key in [&code, &class] {
+        if let Some(verdict) = probe.status.get(key) {
+            return Detection::new(*verdict, &format!("status {}", key), Confidence::High);
+        }
     }
-    
-    // TikTok: Existing profiles have "uniqueId" in embedded JSON
-    if url.contains("tiktok.com") {
-        return check_tiktok_availability(body_lower);
+
+    let (verdict, confidence) = match status {
+        404 => (Verdict::Available, Confidence::Medium),
+        200..=299 => (Verdict::Inspect, Confidence::Low),
+        _ => (Verdict::Unknown, Confidence::Low),
+    };
+    Detection::new(verdict, &format!("status {} (default)", code), confidence)
+}
+
+/// Run the lookup's body rules in order, the first matching rule decides
+pub fn evaluate_body(probe: &Probe, username: &str, body: &str) -> Detection {
+    let body_lower = body.to_lowercase();
+    let username_lower = username.to_lowercase();
+    let json = body_json(probe, body);
+
+    probe.rules.iter()
+        .find(|rule| rule_matches(rule, &body_lower, &username_lower, json.as_ref()))
+        .map_or(Detection::new(probe.fallback, "fallback", probe.fallback_confidence), |rule| Detection {
+            verdict: rule.verdict,
+            rule: rule.name.clone(),
+            label: rule.label.as_ref().map(|label| fill_label(label, rule, json.as_ref())),
+            confidence: rule.confidence(),
+        })
+}
+
+/// Evaluate every body rule (not just up to the first match), for debug summaries
+pub fn trace_rules(probe: &Probe, username: &str, body: &str) -> Vec<(String, bool)> {
+    let body_lower = body.to_lowercase();
+    let username_lower = username.to_lowercase();
+    let json = body_json(probe, body);
+
+    probe.rules.iter()
+        .map(|rule| (rule.name.clone(), rule_matches(rule, &body_lower, &username_lower, json.as_ref())))
+        .collect()
+}
+
+/// Check whether every part of a rule holds for the body
+fn rule_matches(rule: &DetectionRule, body_lower: &str, username_lower: &str, json: Option<&serde_json::Value>) -> bool {
+    let marker = |m: &String| m.to_lowercase().replace("{}", username_lower);
+
+    let any_ok = rule.any.is_empty() || rule.any.iter().any(|m| body_lower.contains(&marker(m)));
+    let all_ok = rule.all.iter().all(|m| body_lower.contains(&marker(m)));
+
+    let json_ok = match &rule.json {
+        None => true,
+        Some(check) => {
+            let value = json.and_then(|v| v.pointer(&check.pointer));
+            let exists_ok = check.exists.is_none_or(|exists| value.is_some() == exists);
+            let equals_ok = check.equals.as_ref().is_none_or(|expected| match (value, expected) {
+                (Some(serde_json::Value::String(actual)), serde_json::Value::String(expected)) => {
+                    actual.eq_ignore_ascii_case(&expected.replace("{}", username_lower))
+                }
+                (Some(actual), expected) => actual == expected,
+                (None, _) => false,
+            });
+            exists_ok && equals_ok
+        }
+    };
+
+    any_ok && all_ok && json_ok
+}
+
+/// The JSON the rules check: the first embedded script found, else the whole body
+fn body_json(probe: &Probe, body: &str) -> Option<serde_json::Value> {
+    probe.embedded_json.iter()
+        .find_map(|id| embedded_json(body, id))
+        .or_else(|| serde_json::from_str(body).ok())
+}
+
+/// Parse the JSON inside `<script id="...">` (e.g. TikTok's `__UNIVERSAL_DATA_FOR_REHYDRATION__`)
+pub fn embedded_json(body: &str, id: &str) -> Option<serde_json::Value> {
+    let start = [format!("id=\"{}\"", id), format!("id='{}'", id)].iter()
+        .find_map(|attribute| body.find(attribute.as_str()))?;
+    let content = &body[start..];
+    let content = &content[content.find('>')? + 1..];
+    let end = content.find("</script>")?;
+
+    serde_json::from_str(content[..end].trim()).ok()
+}
+
+/// Rule label with `{value}` replaced by the value at the rule's JSON pointer
+fn fill_label(label: &str, rule: &DetectionRule, json: Option<&serde_json::Value>) -> String {
+    let value = rule.json.as_ref()
+        .and_then(|check| json?.pointer(&check.pointer))
+        .map(|value| match value {
+            serde_json::Value::String(text) => text.clone(),
+            other => other.to_string(),
+        });
+
+    match value {
+        Some(value) => label.replace("{value}", &value),
+        None => label.to_string(),
     }
-    
-    // For other platforms or if no specific pattern matched, assume profile exists
-    Ok(true) // Taken
+}
+
+/// Look up a bundled platform definition by name
+fn bundled_platform(name: &str) -> Result<SocialPlatform> {
+    bundled_platforms()
+        .into_iter()
+        .find(|p| p.name == name)
+        .with_context(|| format!("No bundled platform named {}", name))
 }
 
 /// Check Instagram profile availability based on HTML content
+/// Runs the bundled Instagram profile page rules (the fallback after the JSON API);
+/// returns true when the profile is taken
+/// Login walls and unrecognized pages are errors, never "available"
 #[cfg_attr(test, allow(dead_code))]
 pub fn check_instagram_availability(body_lower: &str, url: &str) -> Result<bool> {
     // Extract username from URL
-    let username_from_url = url.split('/').last().unwrap_or("");
-    
-    // Real profiles have title like: "<title> (@username) • Instagram photos and videos</title>"
-    // Fake/non-existent profiles have title: "<title>Instagram</title>" or "<title>Login • Instagram</title>"
-    
-    // Check if title contains the username (most reliable indicator)
-    // Instagram uses HTML entity &#064; for @ in titles, or regular @
-    // Format: "<title> (@username) • Instagram" or "<title> (&#064;username) • Instagram"
-    let title_contains_username = !username_from_url.is_empty() && 
-        (body_lower.contains(&format!("<title> (@{}", username_from_url).to_lowercase()) ||
-         body_lower.contains(&format!("<title> (&#064;{}", username_from_url).to_lowercase()) ||
-         body_lower.contains(&format!("(@{})", username_from_url).to_lowercase()) ||
-         body_lower.contains(&format!("(&#064;{})", username_from_url).to_lowercase()));
-    
-    // Check for generic Instagram title (indicates profile doesn't exist)
-    let is_generic_title = body_lower.contains("<title>instagram</title>") ||
-        body_lower.contains("<title>login • instagram</title>");
-    
-    if title_contains_username {
-        Ok(true) // Taken - username found in title
-    } else if is_generic_title {
-        Ok(false) // Available - generic Instagram page
-    } else {
-        // Fallback: if we can't determine from title, check for profilepage
-        // (but this is less reliable as it appears on both)
-        if body_lower.contains("profilepage") {
-            Ok(true) // Likely taken
-        } else {
-            Ok(false) // Likely available
-        }
-    }
+    let username_from_url = url.trim_end_matches('/').rsplit('/').next().unwrap_or("");
+    let platform = bundled_platform("instagram")?;
+    let page = platform.fallbacks.first().context("Instagram has no profile page lookup")?;
+
+    conclusive(evaluate_body(page, username_from_url, body_lower), "Instagram")
 }
 
 /// Check TikTok profile availability based on HTML content
+/// Runs the bundled TikTok rules; returns true when the profile is taken
+/// The body must keep its case (the embedded JSON is parsed); captcha pages,
+/// unknown status codes and unrecognized pages are errors, never "available"
 #[cfg_attr(test, allow(dead_code))]
-pub fn check_tiktok_availability(body_lower: &str) -> Result<bool> {
-    // TikTok: Both real and fake profiles return HTTP 200
-    // Real profiles have:
-    //   - "uniqueId":"username" in embedded JSON
-    //   - "statusCode":0
-    // Non-existent profiles have:
-    //   - "statusCode":10221 (or other non-zero codes)
-    //   - "statusMsg":"user banned" or similar error messages
-    //   - No uniqueId field
-    
-    // Check for positive indicators first
-    let has_unique_id = body_lower.contains("\"uniqueid\":\"");
-    let has_status_code_zero = body_lower.contains("\"statuscode\":0");
-    
-    // Check for negative indicators
-    let has_error_status_code = body_lower.contains("\"statuscode\":10221") ||
-                                body_lower.contains("\"statuscode\":10222") ||
-                                body_lower.contains("\"statusmsg\":\"user banned\"") ||
-                                body_lower.contains("\"statusmsg\":\"user not found\"");
-    
-    if has_unique_id && has_status_code_zero {
-        Ok(true) // Taken - profile exists
-    } else if has_error_status_code {
-        Ok(false) // Available - explicit error status
-    } else if has_unique_id {
-        // Has uniqueId but no statusCode check - assume taken (fallback)
-        Ok(true)
-    } else {
-        // No uniqueId and no error status - assume available
-        Ok(false)
+pub fn check_tiktok_availability(body: &str, url: &str) -> Result<bool> {
+    // Extract username from URL ("https://www.tiktok.com/@name")
+    let username_from_url = url.trim_end_matches('/').rsplit('/').next().unwrap_or("").trim_start_matches('@');
+    let platform = bundled_platform("tiktok")?;
+
+    conclusive(evaluate_body(&platform.probe, username_from_url, body), "TikTok")
+}
+
+/// Taken (true) or available (false); every other verdict is an error
+fn conclusive(detection: Detection, platform: &str) -> Result<bool> {
+    match detection.verdict {
+        Verdict::Available => Ok(false),
+        Verdict::Taken | Verdict::Suspended | Verdict::Reserved => Ok(true),
+        verdict => anyhow::bail!(
+            "{} page is inconclusive: {:?} ({}{})",
+            platform,
+            verdict,
+            detection.rule,
+            detection.label.map(|label| format!(", {}", label)).unwrap_or_default()
+        ),
     }
 }
@@ -0,0 +1,150 @@
+//! Keyword-aware TLD recommendations
+//!
+//! EXPRESS CONCERNS:
+//! - Loading the bundled suggestion table and merging user extensions
+//! - Domain hacks for names ending in a TLD string (chexly -> chex.ly)
+//! - Industry keyword and market (ccTLD) suggestions
+//!
+//! DOES NOT:
+//! - Check domain availability (suggestions go through domain module)
+//! - Render UI or format output
+//! - Handle CLI arguments
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use crate::config::BUNDLED_TLD_SUGGESTIONS;
+
+/// Table mapping name features to recommended TLDs
+#[derive(Debug, Default, Deserialize)]
+pub struct SuggestionTable {
+    /// TLD strings a name can end with to form a domain hack
+    #[serde(default)]
+    pub suffixes: Vec<String>,
+    /// Industry keyword -> TLDs
+    #[serde(default)]
+    pub keywords: BTreeMap<String, Vec<String>>,
+    /// Market (country code) -> ccTLDs
+    #[serde(default)]
+    pub markets: BTreeMap<String, Vec<String>>,
+}
+
+impl SuggestionTable {
+    /// Parse a suggestion table from TOML
+    pub fn from_toml(source: &str) -> Result<Self> {
+        toml::from_str(source).context("Failed to parse TLD suggestion table")
+    }
+
+    /// Merge another table into this one, extending existing entries
+    pub fn merge(&mut self, other: SuggestionTable) {
+        push_unique(&mut self.suffixes, other.suffixes);
+        for (keyword, tlds) in other.keywords {
+            push_unique(self.keywords.entry(keyword.to_lowercase()).or_default(), tlds);
+        }
+        for (market, tlds) in other.markets {
+            push_unique(self.markets.entry(market.to_lowercase()).or_default(), tlds);
+        }
+    }
+}
+
+/// Load the bundled suggestion table, extended by an optional user file
+pub fn load_suggestion_table(extra: Option<&Path>) -> Result<SuggestionTable> {
+    let mut table = SuggestionTable::from_toml(BUNDLED_TLD_SUGGESTIONS)?;
+
+    if let Some(path) = extra {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read suggestion table {}", path.display()))?;
+        table.merge(SuggestionTable::from_toml(&source)?);
+    }
+
+    Ok(table)
+}
+
+/// Suggest extra domains for a username
+/// Domains already covered by `checked_tlds` are left out - NO availability checks
+pub fn suggest_domains(username: &str, table: &SuggestionTable, markets: &[String], checked_tlds: &[String]) -> Vec<String> {
+    let name = username.to_lowercase();
+    let mut domains = Vec::new();
+
+    // Domain hacks: "chexly" -> "chex.ly"
+    for suffix in &table.suffixes {
+        let suffix = normalize_tld(suffix);
+        if let Some(stem) = name.strip_suffix(suffix.as_str()) {
+            let stem = stem.trim_end_matches('-');
+            if !stem.is_empty() {
+                domains.push(format!("{}.{}", stem, suffix));
+            }
+        }
+    }
+
+    // Industry keywords: "CoolShop" or "coolshop" -> "coolshop.store"
+    // A keyword has to end where a word does, so "airline" doesn't get ".ai"
+    let tokens = name_tokens(username);
+    for (keyword, tlds) in &table.keywords {
+        if tokens.iter().any(|token| token.ends_with(keyword.as_str())) {
+            domains.extend(tlds.iter().map(|tld| format!("{}.{}", name, normalize_tld(tld))));
+        }
+    }
+
+    // Markets: "--market de" -> "name.de"
+    for market in markets {
+        let market = market.trim().to_lowercase();
+        if market.is_empty() {
+            continue;
+        }
+        match table.markets.get(&market) {
+            Some(tlds) => domains.extend(tlds.iter().map(|tld| format!("{}.{}", name, normalize_tld(tld)))),
+            None => domains.push(format!("{}.{}", name, normalize_tld(&market))),
+        }
+    }
+
+    let checked: Vec<String> = checked_tlds.iter()
+        .map(|tld| format!("{}.{}", name, normalize_tld(tld)))
+        .collect();
+
+    let mut unique = Vec::new();
+    for domain in domains {
+        if !checked.contains(&domain) && !unique.contains(&domain) {
+            unique.push(domain);
+        }
+    }
+    unique
+}
+
+/// Split a name into lowercase words at separators and camelCase humps
+/// "cool-shop" and "CoolShop" give ["cool", "shop"]; "chainmail" stays one word
+fn name_tokens(username: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut previous: Option<char> = None;
+
+    for c in username.chars() {
+        let hump = c.is_uppercase() && previous.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit());
+        if (!c.is_alphanumeric() || hump) && !current.is_empty() {
+            tokens.push(std::mem::take(&mut current));
+        }
+        if c.is_alphanumeric() {
+            current.extend(c.to_lowercase());
+        }
+        previous = Some(c);
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Lowercase a TLD and strip any leading dot
+fn normalize_tld(tld: &str) -> String {
+    tld.trim().trim_start_matches('.').to_lowercase()
+}
+
+/// Append values that are not already present
+fn push_unique(target: &mut Vec<String>, values: Vec<String>) {
+    for value in values {
+        if !target.contains(&value) {
+            target.push(value);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Request pacing and circuit breaking per host
+//!
+//! EXPRESS CONCERNS:
+//! - Token buckets that space out requests to the same host (or shared key)
+//! - Circuit breakers that stop querying a host after repeated blocks
+//! - Keeping that state across checks, so bulk runs stop wasting requests
+//!
+//! DOES NOT:
+//! - Decide availability (only sees the verdicts the rules produced)
+//! - Define limits (delegates to config module)
+//! - Send requests itself (wraps the caller's transport)
+
+use anyhow::Result;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+use crate::config::{platform_host, RateLimit, SocialPlatform, Verdict};
+use crate::http::{HttpRequest, HttpResponse, Transport};
+
+/// Token buckets and breakers, keyed by host or `rate_limit.key`
+/// Share one across checks to carry limits from one username to the next
+#[derive(Debug, Default)]
+pub struct Throttle {
+    hosts: Mutex<HashMap<String, HostState>>,
+}
+
+#[derive(Debug)]
+struct HostState {
+    tokens: f64,
+    refilled: Instant,
+    /// Blocked or rate-limited answers in a row
+    blocks: u32,
+    /// Set once the breaker trips: what the skipped checks report
+    tripped: Option<Verdict>,
+}
+
+impl Throttle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait until the key's bucket has a token, then take it
+    pub async fn acquire(&self, key: &str, limit: &RateLimit) {
+        let Some(rate) = limit.per_second.filter(|rate| *rate > 0.0) else {
+            return;
+        };
+        let burst = limit.burst.max(1) as f64;
+
+        loop {
+            let wait = {
+                let mut hosts = self.hosts.lock().unwrap();
+                let state = hosts.entry(key.to_string()).or_insert_with(|| HostState::new(burst));
+                let now = Instant::now();
+                state.tokens = (state.tokens + now.duration_since(state.refilled).as_secs_f64() * rate).min(burst);
+                state.refilled = now;
+                if state.tokens >= 1.0 {
+                    state.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - state.tokens) / rate)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Count a lookup's verdict toward the key's breaker
+    /// Blocks and rate limits add up; any real answer resets the count
+    pub fn record(&self, key: &str, limit: &RateLimit, verdict: Verdict) {
+        let mut hosts = self.hosts.lock().unwrap();
+        let state = hosts.entry(key.to_string()).or_insert_with(|| HostState::new(limit.burst.max(1) as f64));
+
+        match verdict {
+            Verdict::Blocked | Verdict::RateLimited => {
+                state.blocks += 1;
+                if limit.trip_after > 0 && state.blocks >= limit.trip_after {
+                    state.tripped = Some(verdict);
+                }
+            }
+            // Errors and inconclusive answers say nothing either way
+            Verdict::Unknown | Verdict::Inspect | Verdict::Next => {}
+            _ => state.blocks = 0,
+        }
+    }
+
+    /// The verdict to report without asking, once the key's breaker has tripped
+    pub fn tripped(&self, key: &str) -> Option<Verdict> {
+        self.hosts.lock().unwrap().get(key).and_then(|state| state.tripped)
+    }
+
+    /// A transport that takes a token from the platform's bucket before every request
+    pub fn wrap<'a>(&'a self, inner: &'a dyn Transport, platform: &'a SocialPlatform) -> Throttled<'a> {
+        Throttled { throttle: self, inner, key: throttle_key(platform), limit: &platform.rate_limit }
+    }
+}
+
+impl HostState {
+    fn new(burst: f64) -> Self {
+        Self { tokens: burst, refilled: Instant::now(), blocks: 0, tripped: None }
+    }
+}
+
+/// A transport paced by a platform's token bucket
+pub struct Throttled<'a> {
+    throttle: &'a Throttle,
+    inner: &'a dyn Transport,
+    key: String,
+    limit: &'a RateLimit,
+}
+
+impl Transport for Throttled<'_> {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+        async move {
+            self.throttle.acquire(&self.key, self.limit).await;
+            self.inner.send(request).await
+        }
+        .boxed()
+    }
+
+    fn can_retry(&self) -> bool {
+        self.inner.can_retry()
+    }
+
+    fn resolves<'a>(&'a self, host: &'a str, proxy: Option<&'a str>) -> BoxFuture<'a, bool> {
+        self.inner.resolves(host, proxy)
+    }
+}
+
+/// Bucket and breaker key: `rate_limit.key`, else the host of the primary lookup
+/// ("{}.substack.com" counts as substack.com)
+pub fn throttle_key(platform: &SocialPlatform) -> String {
+    platform.rate_limit.key.clone()
+        .or_else(|| platform_host(platform))
+        .unwrap_or_else(|| platform.name.clone())
+}
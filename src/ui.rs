@@ -1,18 +1,18 @@
-/// UI utilities for elegant text dividers and styling with multi-color ASCII art
-/// 
-/// EXPRESS CONCERNS:
-/// - Rendering all visual output (boxes, tables, decorative elements)
-/// - ASCII art patterns and colorization
-/// - Terminal width detection and formatting
-/// - Table rendering with proper alignment
-/// - Debug output formatting
-/// 
-/// DOES NOT:
-/// - Perform domain or social media checks
-/// - Make HTTP requests
-/// - Parse CLI arguments
-/// - Validate usernames
-/// - Manage business logic
+//! UI utilities for elegant text dividers and styling with multi-color ASCII art
+//! 
+//! EXPRESS CONCERNS:
+//! - Rendering all visual output (boxes, tables, decorative elements)
+//! - ASCII art patterns and colorization
+//! - Terminal width detection and formatting
+//! - Table rendering with proper alignment
+//! - Debug output formatting
+//! 
+//! DOES NOT:
+//! - Perform domain or social media checks
+//! - Make HTTP requests
+//! - Parse CLI arguments
+//! - Validate usernames
+//! - Manage business logic
 
 use colored::*;
 use rand::prelude::*;
@@ -595,91 +595,146 @@ pub fn add_decorative_fill(line: &str) -> String {
 }
 
 /// Render domain results in a formatted table with decorative box
-pub fn render_domain_results(results: &[crate::domain::DomainResult]) {
-    
-    println!();
-    let domains_box_pattern = Dividers::box_pattern();
-    
+/// The title names the box (e.g. "domains", "suggested")
+/// High-confidence answers come first, heuristic ones below a divider
+pub fn render_domain_results(results: &[crate::domain::DomainResult], title: &str) {
+    use crate::config::Confidence;
+
     let mut table = Table::new();
     table.load_preset(comfy_table::presets::NOTHING);
     table.set_content_arrangement(comfy_table::ContentArrangement::Dynamic);
     
-    for result in results {
+    let (confident, heuristic): (Vec<_>, Vec<_>) = results.iter().partition(|r| r.confidence == Confidence::High || r.available.is_none());
+    for (i, result) in confident.iter().chain(&heuristic).enumerate() {
+        if i == confident.len() {
+            table.add_row(vec![heuristic_divider()]);
+        }
         let status_cell = match result.available {
             Some(true) => Cell::new("AVAILABLE").fg(comfy_table::Color::Green),
             Some(false) => Cell::new("TAKEN").fg(comfy_table::Color::Red),
             None => Cell::new("UNKNOWN").fg(comfy_table::Color::Yellow),
         };
-        table.add_row(vec![Cell::new(&result.domain).fg(comfy_table::Color::White), status_cell]);
-    }
-    
-    // Render table in box with header
-    let table_str = format!("{}", table);
-    let header_text = "domains".bright_cyan().to_string();
-    let header_width = Dividers::strip_ansi_codes(&header_text).chars().count();
-    let table_width = table_str.lines()
-        .map(|l| Dividers::strip_ansi_codes(l).chars().count())
-        .max()
-        .unwrap_or(50)
-        .max(header_width);
-    
-    let (box_top, box_left, box_right, box_bottom, actual_box_width) = Dividers::create_box_with_pattern(&" ".repeat(table_width), Some(domains_box_pattern));
-    println!("{}", add_decorative_fill(&box_top));
-    
-    // Render header centered - use actual_box_width for consistency
-    let header_padding = actual_box_width.saturating_sub(header_width + 2);
-    let header_left_padding = header_padding / 2;
-    let header_right_padding = header_padding - header_left_padding;
-    let header_line = format!("{}{}{}{}", 
-        box_left,
-        " ".repeat(header_left_padding),
-        header_text,
-        " ".repeat(header_right_padding) + &box_right.to_string());
-    println!("{}", add_decorative_fill(&header_line));
-    
-    // Render table rows - use actual_box_width for consistency
-    for line in table_str.lines() {
-        let line_width = Dividers::strip_ansi_codes(line).chars().count();
-        let padding = actual_box_width.saturating_sub(line_width + 2);
-        let row_line = format!("{}{}{}{}", box_left, line, " ".repeat(padding), box_right);
-        println!("{}", add_decorative_fill(&row_line));
+        let mut row = vec![Cell::new(&result.domain).fg(comfy_table::Color::White), status_cell];
+        let mut detail = Vec::new();
+        if i >= confident.len() {
+            detail.push(format!("{}, {} confidence", result.rule, result.confidence));
+        }
+        detail.extend(retry_note(result.retries));
+        if !detail.is_empty() {
+            row.push(Cell::new(detail.join(" · ")).fg(comfy_table::Color::DarkGrey));
+        }
+        table.add_row(row);
     }
     
-    println!("{}", add_decorative_fill(&box_bottom));
+    render_table_box(&title.bright_cyan().to_string(), &table);
 }
 
-/// Render social media results in a formatted table with decorative box
+/// Render social media results in formatted tables with decorative boxes
+/// One box per platform group (social, code, registries, ...), in order of first appearance
+/// High-confidence results come first, heuristic ones below a divider with their deciding rule
 pub fn render_social_results(results: &[crate::social::SocialResult]) {
+    use crate::config::Confidence;
     use crate::social::SocialStatus;
     
-    println!();
-    let social_box_pattern = Dividers::box_pattern();
-    
-    let mut table = Table::new();
-    table.load_preset(comfy_table::presets::NOTHING);
-    table.set_content_arrangement(comfy_table::ContentArrangement::Dynamic);
-    
+    let mut groups: Vec<&str> = Vec::new();
     for result in results {
-        let status_cell = match result.status {
-            SocialStatus::Available => Cell::new("AVAILABLE").fg(comfy_table::Color::Green),
-            SocialStatus::Taken => Cell::new("TAKEN").fg(comfy_table::Color::Red),
-            SocialStatus::Invalid => Cell::new("INVALID").fg(comfy_table::Color::AnsiValue(208)),
-            SocialStatus::Unknown => Cell::new("UNKNOWN").fg(comfy_table::Color::Yellow),
-        };
-        table.add_row(vec![Cell::new(&result.platform).fg(comfy_table::Color::White), status_cell]);
+        if !groups.contains(&result.group.as_str()) {
+            groups.push(&result.group);
+        }
     }
-    
-    // Render table in box with header
+
+    for group in groups {
+        let mut table = Table::new();
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_content_arrangement(comfy_table::ContentArrangement::Dynamic);
+        
+        // Unknown results carry no claim, so they stay with the confident ones
+        let (confident, heuristic): (Vec<_>, Vec<_>) = results.iter()
+            .filter(|r| r.group == group)
+            .partition(|r| r.confidence == Confidence::High || r.status == SocialStatus::Unknown);
+
+        for (i, result) in confident.iter().chain(&heuristic).enumerate() {
+            if i == confident.len() {
+                table.add_row(vec![heuristic_divider()]);
+            }
+            let (label, color) = social_status_style(&result.status);
+            let status_cell = Cell::new(label).fg(color);
+            let mut detail = match (&result.detail, &result.checked_as) {
+                (Some(detail), Some(name)) => format!("{} (as {})", detail, name),
+                (Some(detail), None) => detail.clone(),
+                (None, Some(name)) => format!("as {}", name),
+                (None, None) => String::new(),
+            };
+            if i >= confident.len() {
+                let basis = format!("{}, {} confidence", result.rule, result.confidence);
+                detail = if detail.is_empty() { basis } else { format!("{} · {}", detail, basis) };
+            }
+            for note in [signal_note(result), retry_note(result.retries)].into_iter().flatten() {
+                detail = if detail.is_empty() { note } else { format!("{} · {}", detail, note) };
+            }
+            let mut row = vec![
+                Cell::new(&result.platform).fg(comfy_table::Color::White),
+                status_cell,
+                Cell::new(detail).fg(comfy_table::Color::DarkGrey),
+            ];
+            if let Some(notes) = &result.notes {
+                row.push(Cell::new(notes).fg(comfy_table::Color::DarkGrey));
+            }
+            table.add_row(row);
+        }
+        
+        render_table_box(&group.bright_magenta().to_string(), &table);
+    }
+}
+
+/// "2/3 signals agree, oembed says available" when a platform's signals disagree
+/// Nothing when they agree, and nothing for a split vote (its label says it)
+fn signal_note(result: &crate::social::SocialResult) -> Option<String> {
+    use crate::social::SocialStatus;
+
+    if result.status == SocialStatus::Unknown {
+        return None;
+    }
+    let voting = result.signals.iter().filter(|vote| vote.votes()).count();
+    let dissent: Vec<String> = result.signals.iter()
+        .filter(|vote| vote.disagrees_with(result.status))
+        .map(|vote| {
+            let says = social_status_style(&SocialStatus::from_verdict(vote.verdict)).0.to_lowercase();
+            format!("{} says {}", vote.signal, says)
+        })
+        .collect();
+
+    (!dissent.is_empty()).then(|| format!("{}/{} signals agree, {}", voting - dissent.len(), voting, dissent.join(", ")))
+}
+
+/// "1 retry" / "3 retries", nothing when the first attempt answered
+fn retry_note(retries: u32) -> Option<String> {
+    match retries {
+        0 => None,
+        1 => Some("1 retry".to_string()),
+        n => Some(format!("{} retries", n)),
+    }
+}
+
+/// Divider row between high-confidence and heuristic results
+fn heuristic_divider() -> Cell {
+    Cell::new("~ heuristic ~").fg(comfy_table::Color::DarkGrey)
+}
+
+/// Render a results table inside a decorative box with a centered header
+fn render_table_box(header_text: &str, table: &Table) {
+    println!();
+    let box_pattern = Dividers::box_pattern();
+
     let table_str = format!("{}", table);
-    let header_text = "social".bright_magenta().to_string();
-    let header_width = Dividers::strip_ansi_codes(&header_text).chars().count();
+    let header_width = Dividers::strip_ansi_codes(header_text).chars().count();
     let table_width = table_str.lines()
         .map(|l| Dividers::strip_ansi_codes(l).chars().count())
         .max()
         .unwrap_or(50)
         .max(header_width);
     
-    let (box_top, box_left, box_right, box_bottom, actual_box_width) = Dividers::create_box_with_pattern(&" ".repeat(table_width), Some(social_box_pattern));
+    let (box_top, box_left, box_right, box_bottom, actual_box_width) = Dividers::create_box_with_pattern(&" ".repeat(table_width), Some(box_pattern));
     println!("{}", add_decorative_fill(&box_top));
     
     // Render header centered - use actual_box_width for consistency
@@ -704,44 +759,58 @@ pub fn render_social_results(results: &[crate::social::SocialResult]) {
     println!("{}", add_decorative_fill(&box_bottom));
 }
 
-/// Print debug information for Instagram responses
-/// This is UI concern - formatting debug output for display
-#[allow(dead_code)]
-pub fn print_instagram_debug(body: &str, body_lower: &str, url: &str) {
-    let username_from_url = url.split('/').last().unwrap_or("");
-    println!("\n{}", "=== DEBUG: Instagram Response ===".yellow());
-    println!("Body length: {} bytes", body.len());
-    println!("Username from URL: {}", username_from_url);
-    
-    // Check title patterns
-    let title_contains_username = !username_from_url.is_empty() && 
-        (body_lower.contains(&format!("<title> (@{}", username_from_url).to_lowercase()) ||
-         body_lower.contains(&format!("<title> (&#064;{}", username_from_url).to_lowercase()) ||
-         body_lower.contains(&format!("(@{})", username_from_url).to_lowercase()) ||
-         body_lower.contains(&format!("(&#064;{})", username_from_url).to_lowercase()));
-    let is_generic_title = body_lower.contains("<title>instagram</title>") ||
-        body_lower.contains("<title>login • instagram</title>");
-    
-    println!("Title contains username: {}", title_contains_username);
-    println!("Is generic title: {}", is_generic_title);
-    println!("Contains 'profilepage': {}", body_lower.contains("profilepage"));
-    
-    // Extract title for display
-    if let Some(title_start) = body_lower.find("<title>") {
-        if let Some(title_end) = body_lower[title_start..].find("</title>") {
-            let title = &body_lower[title_start..title_start + title_end + 8];
-            println!("Title tag: {}", title);
+/// Print a per-platform debug summary: every request and which rules matched
+pub fn render_debug_summary(results: &[crate::social::SocialResult], dir: &std::path::Path) {
+    println!();
+    println!("{}", format!("=== DEBUG: responses saved to {} ===", dir.display()).yellow());
+
+    for result in results {
+        println!();
+        println!("{} {}", result.platform.bright_white(), format!("→ {} ({}, {} confidence)", social_status_style(&result.status).0, result.rule, result.confidence).dimmed());
+        if result.captures.is_empty() {
+            println!("  {}", "no requests made".dimmed());
+        }
+
+        for capture in &result.captures {
+            let outcome = match (&capture.error, capture.status) {
+                (Some(error), _) => error.red().to_string(),
+                (None, Some(status)) => status.to_string(),
+                (None, None) => "no response".to_string(),
+            };
+            println!("  {} {} → {}", capture.method, capture.url, outcome);
+            for url in &capture.redirects {
+                println!("    {} {}", "redirect".dimmed(), url);
+            }
+            if !capture.rules.is_empty() {
+                let rules = capture.rules.iter()
+                    .map(|(name, matched)| if *matched { format!("✓ {}", name).green().to_string() } else { format!("✗ {}", name).dimmed().to_string() })
+                    .collect::<Vec<_>>()
+                    .join("  ");
+                println!("    {}", rules);
+            }
+            println!("    {}", format!("{:?} via {}", capture.verdict, capture.decided_by).dimmed());
+            match &capture.saved_to {
+                Some(path) => println!("    {} {}", "saved".dimmed(), path.display()),
+                None => println!("    {}", "not saved".red()),
+            }
         }
     }
-    
-    // Save to file for inspection
-    use std::fs;
-    let filename = format!("/tmp/instagram_debug_{}.html", username_from_url);
-    if let Err(e) = fs::write(&filename, body) {
-        println!("Failed to write debug file: {}", e);
-    } else {
-        println!("Full response saved to: {}", filename);
+
+    println!();
+}
+
+/// Label and color for each social status
+fn social_status_style(status: &crate::social::SocialStatus) -> (&'static str, comfy_table::Color) {
+    use crate::social::SocialStatus;
+
+    match status {
+        SocialStatus::Available => ("AVAILABLE", comfy_table::Color::Green),
+        SocialStatus::Taken => ("TAKEN", comfy_table::Color::Red),
+        SocialStatus::Suspended => ("SUSPENDED", comfy_table::Color::DarkRed),
+        SocialStatus::Reserved => ("RESERVED", comfy_table::Color::Magenta),
+        SocialStatus::Blocked => ("BLOCKED", comfy_table::Color::Blue),
+        SocialStatus::RateLimited => ("RATE LIMITED", comfy_table::Color::Cyan),
+        SocialStatus::Invalid => ("INVALID", comfy_table::Color::AnsiValue(208)),
+        SocialStatus::Unknown => ("UNKNOWN", comfy_table::Color::Yellow),
     }
-    
-    println!("{}", "================================\n".yellow());
 }
@@ -1,87 +1,365 @@
-/// Username validation functions for different social media platforms
-/// 
-/// EXPRESS CONCERNS:
-/// - Validating username format against platform-specific rules
-/// - Instagram, YouTube, TikTok username validation
-/// - Pure validation logic with no side effects
-/// 
-/// DOES NOT:
-/// - Make HTTP requests
-/// - Render UI or print output
-/// - Check actual availability (only validates format)
-/// - Handle errors beyond returning Ok/Err
+//! Username validation functions for different social media platforms
+//! 
+//! EXPRESS CONCERNS:
+//! - Validating username format against platform-specific rules
+//! - Declarative, versioned username rulesets loaded with platform definitions
+//! - Instagram, YouTube, TikTok username validation
+//! - GitHub, GitLab, Codeberg, Bitbucket username validation
+//! - X, Threads, Bluesky, Mastodon handle validation
+//! - Telegram, Snapchat, Pinterest, Reddit (users and subreddits) validation
+//! - Twitch, Kick, Steam, Roblox username validation
+//! - Pure validation logic with no side effects
+//! 
+//! DOES NOT:
+//! - Make HTTP requests
+//! - Render UI or print output
+//! - Check actual availability (only validates format)
+//! - Handle errors beyond returning Ok/Err
 
-/// Validates Instagram username format
-/// Rules:
-/// - 1-30 characters
-/// - Letters, numbers, periods, underscores only
-/// - Can't start or end with period
-/// - Can't have consecutive periods
-pub fn validate_instagram_username(username: &str) -> Result<(), ()> {
-    if username.is_empty() || username.len() > 30 {
-        return Err(());
+use serde::Deserialize;
+use std::sync::OnceLock;
+use crate::config::bundled_platforms;
+
+/// Declarative username format rules for a platform
+/// Rulesets can cite where the rules come from and when they were last checked
+#[derive(Debug, Clone, Deserialize)]
+pub struct UsernameRules {
+    /// Bumped whenever the rules change
+    #[serde(default = "default_version")]
+    pub version: u32,
+    /// Date the rules were last checked against `source` (YYYY-MM-DD)
+    #[serde(default)]
+    pub verified: Option<String>,
+    /// The page that states the rules (docs, or the code that enforces them)
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default = "default_min_length")]
+    pub min_length: usize,
+    #[serde(default)]
+    pub max_length: Option<usize>,
+    /// Characters allowed besides letters and digits (None = any character)
+    #[serde(default)]
+    pub allowed_symbols: Option<String>,
+    /// Only ASCII letters and digits count as alphanumeric
+    #[serde(default)]
+    pub ascii_only: bool,
+    /// Characters the username can't start with
+    #[serde(default)]
+    pub no_leading: String,
+    /// Characters the username can't end with
+    #[serde(default)]
+    pub no_trailing: String,
+    /// Substrings the username can't contain (e.g. "..")
+    #[serde(default)]
+    pub forbidden_sequences: Vec<String>,
+    #[serde(default)]
+    pub must_start_with_letter: bool,
+    /// Regex the username must match; definitions whose pattern doesn't compile are rejected
+    #[serde(default)]
+    pub pattern: Option<Pattern>,
+}
+
+/// A username regex, compiled once when its definition is loaded
+#[derive(Debug, Clone)]
+pub struct Pattern(fancy_regex::Regex);
+
+impl Pattern {
+    pub fn new(source: &str) -> anyhow::Result<Self> {
+        fancy_regex::Regex::new(source)
+            .map(Self)
+            .map_err(|e| anyhow::anyhow!("invalid pattern {:?}: {}", source, e))
     }
-    
-    if username.starts_with('.') || username.ends_with('.') {
-        return Err(());
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
     }
-    
-    if username.contains("..") {
-        return Err(());
+
+    /// A match that gives up (backtrack limit) counts as a match
+    pub fn is_match(&self, username: &str) -> bool {
+        self.0.is_match(username).unwrap_or(true)
     }
-    
-    if !username.chars().all(|c| c.is_alphanumeric() || c == '.' || c == '_') {
-        return Err(());
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        Pattern::new(&source).map_err(serde::de::Error::custom)
     }
-    
-    Ok(())
 }
 
-/// Validates YouTube username format
-/// Rules:
-/// - 3-20 characters
-/// - Letters, numbers, hyphens, underscores only
-/// - Can't start or end with hyphen or underscore
-pub fn validate_youtube_username(username: &str) -> Result<(), ()> {
-    if username.len() < 3 || username.len() > 20 {
-        return Err(());
+impl Default for UsernameRules {
+    fn default() -> Self {
+        Self {
+            version: default_version(),
+            verified: None,
+            source: None,
+            min_length: default_min_length(),
+            max_length: None,
+            allowed_symbols: None,
+            ascii_only: false,
+            no_leading: String::new(),
+            no_trailing: String::new(),
+            forbidden_sequences: Vec::new(),
+            must_start_with_letter: false,
+            pattern: None,
+        }
     }
-    
-    if username.starts_with('-') || username.starts_with('_') ||
-       username.ends_with('-') || username.ends_with('_') {
-        return Err(());
+}
+
+fn default_version() -> u32 {
+    1
+}
+
+fn default_min_length() -> usize {
+    1
+}
+
+/// A username rule a name breaks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    TooShort { min: usize, length: usize },
+    TooLong { max: usize, length: usize },
+    /// A character outside letters, digits and the allowed symbols (position counts from 1)
+    BadCharacter { character: char, position: usize },
+    BadStart(char),
+    BadEnd(char),
+    ForbiddenSequence(String),
+    MustStartWithLetter,
+    PatternMismatch,
+    /// No bundled platform by that name, so there are no rules to check against
+    UnknownPlatform(String),
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::TooShort { min, length } => write!(f, "too short ({} < {})", length, min),
+            Violation::TooLong { max, length } => write!(f, "too long ({} > {})", length, max),
+            Violation::BadCharacter { character, position } => {
+                write!(f, "bad character {:?} at position {}", character, position)
+            }
+            Violation::BadStart(c) => write!(f, "can't start with {:?}", c),
+            Violation::BadEnd(c) => write!(f, "can't end with {:?}", c),
+            Violation::ForbiddenSequence(seq) => match repeated_symbol(seq) {
+                Some(name) => write!(f, "consecutive {}", name),
+                None => write!(f, "can't contain {:?}", seq),
+            },
+            Violation::MustStartWithLetter => write!(f, "must start with a letter"),
+            Violation::PatternMismatch => write!(f, "doesn't match the platform's pattern"),
+            Violation::UnknownPlatform(name) => write!(f, "no bundled platform named {:?}", name),
+        }
     }
-    
-    if !username.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
-        return Err(());
+}
+
+/// "periods" for "..", "underscores" for "__"; None for other sequences
+fn repeated_symbol(seq: &str) -> Option<&'static str> {
+    let first = seq.chars().next()?;
+    if seq.chars().count() < 2 || !seq.chars().all(|c| c == first) {
+        return None;
+    }
+    match first {
+        '.' => Some("periods"),
+        '_' => Some("underscores"),
+        '-' => Some("hyphens"),
+        _ => None,
     }
-    
-    Ok(())
 }
 
-/// Validates TikTok username format
-/// Rules:
-/// - 1-24 characters
-/// - Letters, numbers, underscores, periods
-/// - Must start with a letter
-/// - Can't have consecutive underscores
-pub fn validate_tiktok_username(username: &str) -> Result<(), ()> {
-    if username.is_empty() || username.len() > 24 {
-        return Err(());
+/// Every rule a username breaks, in the order the rules are checked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub violations: Vec<Violation>,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reasons: Vec<String> = self.violations.iter().map(|v| v.to_string()).collect();
+        write!(f, "{}", reasons.join(", "))
     }
-    
-    if !username.chars().next().map_or(false, |c| c.is_alphabetic()) {
-        return Err(());
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Validates a username against a declarative ruleset
+/// Every broken rule is reported, not just the first
+pub fn validate_username(username: &str, rules: &UsernameRules) -> Result<(), ValidationError> {
+    let mut violations = Vec::new();
+
+    let length = username.chars().count();
+    if length < rules.min_length {
+        violations.push(Violation::TooShort { min: rules.min_length, length });
     }
-    
-    if username.contains("__") {
-        return Err(());
+    if let Some(max) = rules.max_length.filter(|max| length > *max) {
+        violations.push(Violation::TooLong { max, length });
+    }
+
+    if let Some(c) = username.chars().next().filter(|c| rules.no_leading.contains(*c)) {
+        violations.push(Violation::BadStart(c));
+    }
+    if let Some(c) = username.chars().last().filter(|c| rules.no_trailing.contains(*c)) {
+        violations.push(Violation::BadEnd(c));
+    }
+
+    for seq in rules.forbidden_sequences.iter().filter(|seq| username.contains(seq.as_str())) {
+        violations.push(Violation::ForbiddenSequence(seq.clone()));
     }
-    
-    if !username.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.') {
-        return Err(());
+
+    let is_letter = |c: char| if rules.ascii_only { c.is_ascii_alphabetic() } else { c.is_alphabetic() };
+    if rules.must_start_with_letter && !username.chars().next().is_some_and(is_letter) {
+        violations.push(Violation::MustStartWithLetter);
+    }
+
+    if let Some(symbols) = &rules.allowed_symbols {
+        let is_alphanumeric = |c: char| if rules.ascii_only { c.is_ascii_alphanumeric() } else { c.is_alphanumeric() };
+        for (i, character) in username.chars().enumerate() {
+            if !is_alphanumeric(character) && !symbols.contains(character) {
+                violations.push(Violation::BadCharacter { character, position: i + 1 });
+            }
+        }
+    }
+
+    if rules.pattern.as_ref().is_some_and(|pattern| !pattern.is_match(username)) {
+        violations.push(Violation::PatternMismatch);
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError { violations })
+    }
+}
+
+/// Validates a username against a bundled platform's ruleset
+/// Platforms without a ruleset accept any username; unknown platform names are an error
+pub fn validate_platform_username(platform: &str, username: &str) -> Result<(), ValidationError> {
+    static BUNDLED_RULES: OnceLock<Vec<(String, Option<UsernameRules>)>> = OnceLock::new();
+    let rules = BUNDLED_RULES.get_or_init(|| {
+        bundled_platforms().into_iter().map(|p| (p.name, p.validation)).collect()
+    });
+
+    match rules.iter().find(|(name, _)| name == platform) {
+        Some((_, Some(rules))) => validate_username(username, rules),
+        Some((_, None)) => Ok(()),
+        None => Err(ValidationError { violations: vec![Violation::UnknownPlatform(platform.to_string())] }),
     }
-    
-    Ok(())
 }
 
+/// Validates Instagram username format
+/// Rules: the `instagram` ruleset in `platforms/social.toml`
+pub fn validate_instagram_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("instagram", username)
+}
+
+/// Validates YouTube handle format
+/// Rules: the `youtube` ruleset in `platforms/social.toml`
+pub fn validate_youtube_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("youtube", username)
+}
+
+/// Validates TikTok username format
+/// Rules: the `tiktok` ruleset in `platforms/social.toml`
+pub fn validate_tiktok_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("tiktok", username)
+}
+
+/// Validates GitHub username format
+/// Rules: the `github` ruleset in `platforms/code.toml`
+pub fn validate_github_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("github", username)
+}
+
+/// Validates GitLab username format
+/// Rules: the `gitlab` ruleset in `platforms/code.toml`
+pub fn validate_gitlab_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("gitlab", username)
+}
+
+/// Validates Codeberg username format
+/// Rules: the `codeberg` ruleset in `platforms/code.toml`
+pub fn validate_codeberg_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("codeberg", username)
+}
+
+/// Validates Bitbucket workspace ID format
+/// Rules: the `bitbucket` ruleset in `platforms/code.toml`
+pub fn validate_bitbucket_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("bitbucket", username)
+}
+
+/// Validates X (Twitter) username format
+/// Rules: the `x` ruleset in `platforms/social.toml`
+pub fn validate_x_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("x", username)
+}
+
+/// Validates Threads username format
+/// Rules: the `threads` ruleset in `platforms/social.toml`
+pub fn validate_threads_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("threads", username)
+}
+
+/// Validates Bluesky handle format (the name part of name.bsky.social)
+/// Rules: the `bluesky` ruleset in `platforms/social.toml`
+pub fn validate_bluesky_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("bluesky", username)
+}
+
+/// Validates Mastodon username format (the local part of @name@instance)
+/// Rules: the `mastodon` ruleset in `platforms/social.toml`
+pub fn validate_mastodon_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("mastodon", username)
+}
+
+/// Validates Twitch username format
+/// Rules: the `twitch` ruleset in `platforms/gaming.toml`
+pub fn validate_twitch_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("twitch", username)
+}
+
+/// Validates Kick username format
+/// Rules: the `kick` ruleset in `platforms/gaming.toml`
+pub fn validate_kick_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("kick", username)
+}
+
+/// Validates Steam custom URL (vanity /id/<name>) format
+/// Rules: the `steam` ruleset in `platforms/gaming.toml`
+pub fn validate_steam_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("steam", username)
+}
+
+/// Validates Roblox username format
+/// Rules: the `roblox` ruleset in `platforms/gaming.toml`
+pub fn validate_roblox_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("roblox", username)
+}
+
+/// Validates Telegram username format
+/// Rules: the `telegram` ruleset in `platforms/social.toml`
+pub fn validate_telegram_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("telegram", username)
+}
+
+/// Validates Snapchat username format
+/// Rules: the `snapchat` ruleset in `platforms/social.toml`
+pub fn validate_snapchat_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("snapchat", username)
+}
+
+/// Validates Pinterest username format
+/// Rules: the `pinterest` ruleset in `platforms/social.toml`
+pub fn validate_pinterest_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("pinterest", username)
+}
+
+/// Validates Reddit username format
+/// Rules: the `reddit` ruleset in `platforms/social.toml`
+pub fn validate_reddit_username(username: &str) -> Result<(), ValidationError> {
+    validate_platform_username("reddit", username)
+}
+
+/// Validates subreddit name format
+/// Rules: the `subreddit` ruleset in `platforms/social.toml`
+pub fn validate_subreddit_name(name: &str) -> Result<(), ValidationError> {
+    validate_platform_username("subreddit", name)
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use chexx0r::*;
+use chexx0r::config::get_channel_preset;
+use chexx0r::registry::normalize_name;
+use common::find_bundled;
+
+
+#[test]
+fn test_channel_presets() {
+    assert_eq!(get_channel_preset("cli"), ["docker-hub", "docker-official", "homebrew", "aur"]);
+    assert_eq!(get_channel_preset("DESKTOP"), ["flathub", "snapcraft", "homebrew-cask", "aur"]);
+    assert_eq!(get_channel_preset("docker-hub, AUR"), ["docker-hub", "aur"]);
+
+    // Every preset entry must name a bundled channel
+    for name in get_channel_preset("all") {
+        assert_eq!(find_bundled(&name).group, "channels", "{} isn't a channel", name);
+    }
+}
+
+// Channel names after normalization: (channel, name, should be valid)
+const CHANNEL_NAMES: &[(&str, &str, bool)] = &[
+    ("docker-hub", "chexx0r", true),
+    ("docker-hub", "abc", false),              // Namespaces need 4+ chars
+    ("docker-hub", "chex-x0r", false),         // No separators in namespaces
+    ("docker-official", "node-red", true),
+    ("homebrew", "python@3.12", true),
+    ("homebrew-cask", "visual-studio-code", true),
+    ("homebrew-cask", "bad--token", false),
+    ("flathub", "org.gnome.Maps", true),
+    ("flathub", "io.github.chexx0r.chexx0r", true),
+    ("flathub", "org.2048.Game", false),       // Components can't start with a digit
+    ("flathub", "com.example", false),         // Needs 3+ components
+    ("snapcraft", "chexx0r", true),
+    ("snapcraft", "1234", false),              // Needs a letter
+    ("snapcraft", "-chexx0r", false),
+    ("aur", "python-requests", true),
+    ("aur", ".hidden", false),
+];
+
+#[test]
+fn test_channel_validation() {
+    let mut failures = Vec::new();
+
+    for (name, candidate, valid) in CHANNEL_NAMES {
+        let rules = find_bundled(name).validation.unwrap();
+        if validate_username(candidate, &rules).is_ok() != *valid {
+            failures.push(format!("✗ {}-{}: expected {}", name, candidate, if *valid { "VALID" } else { "INVALID" }));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} validation failures:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+#[test]
+fn test_channel_lookups() {
+    assert_eq!(normalize_name("flathub", "chexx0r"), "io.github.chexx0r.chexx0r");
+    assert_eq!(normalize_name("flathub", "org.gnome.Maps"), "org.gnome.Maps");
+
+    let aur = find_bundled("aur");
+    assert_eq!(evaluate_body(&aur.probe, "yay", r#"{"resultcount":1,"results":[{"Name":"yay"}]}"#).verdict, Verdict::Taken);
+    assert_eq!(evaluate_body(&aur.probe, "ghost", r#"{"resultcount":0,"results":[]}"#).verdict, Verdict::Next);
+    let official = evaluate_body(&aur.fallbacks[0], "pacman", r#"{"results":[{"pkgname":"pacman"}]}"#);
+    assert_eq!((official.verdict, official.label.as_deref()), (Verdict::Taken, Some("official repo")));
+    assert_eq!(evaluate_body(&aur.fallbacks[0], "ghost", r#"{"results":[]}"#).verdict, Verdict::Available);
+
+    let docker = find_bundled("docker-hub");
+    assert_eq!(evaluate_status(&docker.probe, 404), Verdict::Next);
+    assert_eq!(evaluate_status(&docker.fallbacks[0], 404), Verdict::Available);
+}
@@ -0,0 +1,31 @@
+use std::path::Path;
+use std::process::Command;
+
+// Offline run: one platform served from an empty cassette, no domain lookups
+fn run(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_chexx0r"))
+        .current_dir(dir)
+        .args(args)
+        .args(["--skip-domains", "--platforms", "youtube", "--skip-code", "--skip-creators", "--skip-registries", "--replay", "cassette"])
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_debug_flag_takes_no_value() {
+    let dir = std::env::temp_dir().join(format!("chexx0r-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    // `--debug alice`: alice is the username, not a directory
+    let output = run(&dir, &["--debug", "alice"]);
+    assert!(output.status.success(), "--debug <USERNAME> should parse: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("checking: alice"));
+    assert!(dir.join("chexx0r-debug").is_dir(), "--debug should save to the default directory");
+    assert!(!dir.join("alice").exists());
+
+    let output = run(&dir, &["--debug-dir", "traces", "alice"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(dir.join("traces").is_dir(), "--debug-dir should imply --debug");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,70 @@
+// Each test binary uses its own share of these helpers
+#![allow(dead_code)]
+
+use chexx0r::*;
+use futures::future::{self, BoxFuture};
+use futures::FutureExt;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// A bundled platform definition by name; panics when there is none
+pub fn find_bundled(name: &str) -> SocialPlatform {
+    bundled_platforms().into_iter().find(|p| p.name == name)
+        .unwrap_or_else(|| panic!("no bundled platform named {}", name))
+}
+
+/// Canned responses, keeping every request it was sent
+/// Listed URLs get their page; anything else plays `script` in order, its last response repeating
+pub struct FakeTransport {
+    pages: BTreeMap<String, HttpResponse>,
+    script: Mutex<Vec<HttpResponse>>,
+    sent: Mutex<Vec<HttpRequest>>,
+}
+
+impl FakeTransport {
+    /// Pages by URL, `fallback` for the rest
+    pub fn new(pages: &[(&str, HttpResponse)], fallback: HttpResponse) -> Self {
+        let pages = pages.iter().map(|(url, response)| (url.to_string(), response.clone())).collect();
+        Self { pages, script: Mutex::new(vec![fallback]), sent: Mutex::new(Vec::new()) }
+    }
+
+    /// The same responses in order, whatever is asked
+    pub fn scripted(script: Vec<HttpResponse>) -> Self {
+        Self { pages: BTreeMap::new(), script: Mutex::new(script), sent: Mutex::new(Vec::new()) }
+    }
+
+    /// Answer anything unlisted with `response` from now on
+    pub fn set_fallback(&self, response: HttpResponse) {
+        *self.script.lock().unwrap() = vec![response];
+    }
+
+    pub fn sent(&self) -> Vec<HttpRequest> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    pub fn urls(&self) -> Vec<String> {
+        self.sent.lock().unwrap().iter().map(|r| r.url.clone()).collect()
+    }
+}
+
+impl Transport for FakeTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> BoxFuture<'a, anyhow::Result<HttpResponse>> {
+        self.sent.lock().unwrap().push(request.clone());
+        let response = match self.pages.get(&request.url) {
+            Some(page) => page.clone(),
+            None => {
+                let mut script = self.script.lock().unwrap();
+                if script.len() > 1 { script.remove(0) } else { script[0].clone() }
+            }
+        };
+        future::ready(Ok(response)).boxed()
+    }
+}
+
+pub fn response(status: u16, body: &str) -> HttpResponse {
+    HttpResponse { status, body: body.to_string(), ..HttpResponse::default() }
+}
+
+pub fn header(name: &str, value: &str) -> (String, String) {
+    (name.to_string(), value.to_string())
+}
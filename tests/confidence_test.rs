@@ -0,0 +1,72 @@
+mod common;
+
+use chexx0r::*;
+use chexx0r::social::apply_min_confidence;
+use common::find_bundled;
+
+
+// Deciding rules: (platform, status, body, expected rule, expected confidence)
+const CONFIDENCE_CASES: &[(&str, u16, &str, &str, Confidence)] = &[
+    ("youtube", 200, "", "status 2xx", Confidence::High),                   // Listed status class
+    ("telegram", 404, "", "status 404 (default)", Confidence::Medium),      // Built-in default
+    ("instagram", 404, "", "status 404", Confidence::High),
+    ("instagram", 200, r#"{"data":{"user":{"id":"1"}}}"#, "profile-json", Confidence::High),
+    ("instagram", 200, r#"{"data":{"user":null}}"#, "restricted", Confidence::Medium),
+    ("instagram", 200, "<html></html>", "fallback", Confidence::Low),
+    ("github", 200, r#"{"type":"User"}"#, "user", Confidence::High),         // JSON checks default to high
+];
+
+#[test]
+fn test_deciding_rule_and_confidence() {
+    let mut failures = Vec::new();
+
+    for (platform, status, body, rule, confidence) in CONFIDENCE_CASES {
+        let probe = find_bundled(platform).probe;
+        let detection = match status_detection(&probe, *status) {
+            d if d.verdict == Verdict::Inspect => evaluate_body(&probe, "nike", body),
+            d => d,
+        };
+        if detection.rule != *rule || detection.confidence != *confidence {
+            failures.push(format!("✗ {}: expected {}/{:?}, got {}/{:?}", platform, rule, confidence, detection.rule, detection.confidence));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} confidence mismatches:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+fn result(status: SocialStatus, confidence: Confidence) -> SocialResult {
+    SocialResult {
+        platform: "example".to_string(),
+        group: "social".to_string(),
+        status,
+        detail: None,
+        checked_as: None,
+        notes: None,
+        confidence,
+        rule: "example".to_string(),
+        retries: 0,
+        signals: Vec::new(),
+        captures: Vec::new(),
+    }
+}
+
+#[test]
+fn test_min_confidence_demotes_to_unknown() {
+    let mut results = vec![
+        result(SocialStatus::Available, Confidence::Low),
+        result(SocialStatus::Taken, Confidence::Medium),
+        result(SocialStatus::Available, Confidence::High),
+        result(SocialStatus::Invalid, Confidence::High),
+    ];
+    apply_min_confidence(&mut results, Confidence::High);
+
+    let statuses: Vec<SocialStatus> = results.iter().map(|r| r.status).collect();
+    assert_eq!(statuses, [SocialStatus::Unknown, SocialStatus::Unknown, SocialStatus::Available, SocialStatus::Invalid]);
+    assert_eq!(results[0].detail.as_deref(), Some("below high confidence"));
+    assert_eq!(results[0].rule, "example", "the deciding rule is kept");
+
+    assert_eq!("Medium".parse::<Confidence>(), Ok(Confidence::Medium));
+    assert!("certain".parse::<Confidence>().is_err());
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use chexx0r::*;
+use chexx0r::social::tally;
+use common::find_bundled;
+
+fn detection(verdict: Verdict, rule: &str, confidence: Confidence) -> Detection {
+    Detection::new(verdict, rule, confidence)
+}
+
+// (vote, signals' (verdict, confidence) with the primary lookup first, expected verdict, rule, confidence)
+type TallyCase = (Vote, &'static [(Verdict, Confidence)], Verdict, &'static str, Confidence);
+
+const TALLY_CASES: &[TallyCase] = &[
+    // Agreement keeps the best confidence
+    (Vote::Majority, &[(Verdict::Taken, Confidence::Medium), (Verdict::Taken, Confidence::High)], Verdict::Taken, "signal 0", Confidence::High),
+    // Abstaining signals don't count
+    (Vote::Majority, &[(Verdict::Blocked, Confidence::High), (Verdict::Available, Confidence::High)], Verdict::Available, "signal 1", Confidence::High),
+    (Vote::Majority, &[(Verdict::Unknown, Confidence::Low), (Verdict::RateLimited, Confidence::High)], Verdict::Unknown, "signal 0", Confidence::Low),
+    // Majority wins, one step less sure
+    (Vote::Majority, &[(Verdict::Available, Confidence::High), (Verdict::Taken, Confidence::High), (Verdict::Suspended, Confidence::Medium)], Verdict::Taken, "signal 1", Confidence::Medium),
+    (Vote::Majority, &[(Verdict::Available, Confidence::Medium), (Verdict::Available, Confidence::Medium), (Verdict::Taken, Confidence::High)], Verdict::Available, "signal 0", Confidence::Low),
+    (Vote::Majority, &[(Verdict::Available, Confidence::High), (Verdict::Taken, Confidence::High)], Verdict::Unknown, "signals split", Confidence::Low),
+    // Unanimous: any dissent is unknown
+    (Vote::Unanimous, &[(Verdict::Taken, Confidence::High), (Verdict::Taken, Confidence::High), (Verdict::Available, Confidence::Low)], Verdict::Unknown, "signals split", Confidence::Low),
+    (Vote::Unanimous, &[(Verdict::Taken, Confidence::High), (Verdict::Blocked, Confidence::High)], Verdict::Taken, "signal 0", Confidence::High),
+    // Primary: the first lookup decides, dissent lowers confidence
+    (Vote::Primary, &[(Verdict::Suspended, Confidence::High), (Verdict::Available, Confidence::High), (Verdict::Available, Confidence::High)], Verdict::Suspended, "signal 0", Confidence::Medium),
+    (Vote::Primary, &[(Verdict::Blocked, Confidence::High), (Verdict::Available, Confidence::Medium)], Verdict::Available, "signal 1", Confidence::Medium),
+];
+
+#[test]
+fn test_tally() {
+    let mut failures = Vec::new();
+
+    for (i, (vote, signals, verdict, rule, confidence)) in TALLY_CASES.iter().enumerate() {
+        let votes: Vec<(String, Detection)> = signals.iter().enumerate()
+            .map(|(n, (v, c))| (format!("s{}", n), detection(*v, &format!("signal {}", n), *c)))
+            .collect();
+        let got = tally(*vote, &votes);
+        if got.verdict != *verdict || got.rule != *rule || got.confidence != *confidence {
+            failures.push(format!(
+                "✗ case {} ({:?}): expected {:?}/{}/{:?}, got {:?}/{}/{:?}",
+                i, vote, verdict, rule, confidence, got.verdict, got.rule, got.confidence
+            ));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} tally mismatches:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+#[test]
+fn test_signal_definitions() {
+    let defs = config::parse_platforms(r#"
+        [[platforms]]
+        name = "example"
+        url_template = "https://example.com/{}"
+        signal = "profile"
+        vote = "unanimous"
+
+        [[platforms.signals]]
+        signal = "oembed"
+        url_template = "https://example.com/oembed?url=https://example.com/{}"
+
+        [platforms.signals.status]
+        "404" = "available"
+    "#).unwrap();
+
+    assert_eq!(defs[0].vote, Vote::Unanimous);
+    assert_eq!(defs[0].probe.signal.as_deref(), Some("profile"));
+    assert_eq!(defs[0].signals[0].signal.as_deref(), Some("oembed"));
+    assert_eq!(defs[0].lookups().count(), 2);
+
+    let split = tally(Vote::Majority, &[
+        ("a".to_string(), detection(Verdict::Available, "x", Confidence::High)),
+        ("b".to_string(), detection(Verdict::Taken, "y", Confidence::High)),
+    ]);
+    assert_eq!(split.label.as_deref(), Some("1 say available, 1 say taken"));
+
+    // Instagram's web and mobile APIs both have to agree
+    let instagram = find_bundled("instagram");
+    assert_eq!(instagram.vote, Vote::Unanimous);
+}
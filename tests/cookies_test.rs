@@ -0,0 +1,192 @@
+mod common;
+
+use chexx0r::*;
+use chexx0r::cookies::{format_cookies_txt_line, parse_cookies_txt_line, parse_set_cookie};
+use chexx0r::headers::load_header_profile;
+use common::{header, response, FakeTransport};
+use reqwest::Url;
+use std::collections::BTreeMap;
+
+const NOW: u64 = 1_760_000_000;
+
+// (Set-Cookie value, set by, expected (domain, subdomains, path, expires) or None when refused)
+type SetCookieCase = (&'static str, &'static str, Option<(&'static str, bool, &'static str, u64)>);
+
+const SET_COOKIE_CASES: &[SetCookieCase] = &[
+    ("csrftoken=abc; Path=/", "https://www.instagram.com/nike", Some(("www.instagram.com", false, "/", 0))),
+    ("csrftoken=abc; Domain=.instagram.com; Path=/", "https://www.instagram.com/", Some(("instagram.com", true, "/", 0))),
+    ("mid=x; Max-Age=60", "https://www.instagram.com/", Some(("www.instagram.com", false, "/", NOW + 60))),
+    ("mid=x; Max-Age=0", "https://www.instagram.com/", Some(("www.instagram.com", false, "/", 1))),
+    ("mid=x; Expires=Wed, 21-Oct-2026 07:28:00 GMT", "https://www.instagram.com/", Some(("www.instagram.com", false, "/", 1_792_567_680))),
+    ("mid=x; Expires=Wed, 21-Oct-2026 07:28:00 GMT; Max-Age=10", "https://www.instagram.com/", Some(("www.instagram.com", false, "/", NOW + 10))),
+    ("sid=1; Domain=tiktok.com", "https://www.instagram.com/", None),
+    ("sid=1; Domain=gram.com", "https://www.instagram.com/", None),
+    ("sid=1; Domain=com", "https://www.instagram.com/", None),                // A bare TLD reaches every .com site
+    ("sid=1; Domain=.co.uk", "https://www.bbc.co.uk/", None),                 // So does a public suffix
+    ("sid=1; Domain=github.io", "https://chexx0r.github.io/", None),
+    ("sid=1; Domain=github.io", "https://github.io/", Some(("github.io", false, "/", 0))), // The suffix itself stays host-only
+    ("sid=1; Domain=bbc.co.uk", "https://www.bbc.co.uk/", Some(("bbc.co.uk", true, "/", 0))),
+    ("=nameless", "https://www.instagram.com/", None),
+    ("no-equals-sign", "https://www.instagram.com/", None),
+];
+
+#[test]
+fn test_parse_set_cookie() {
+    let mut failures = Vec::new();
+
+    for (value, url, expected) in SET_COOKIE_CASES {
+        let cookie = parse_set_cookie(value, &Url::parse(url).unwrap(), NOW);
+        let got = cookie.as_ref().map(|c| (c.domain.as_str(), c.include_subdomains, c.path.as_str(), c.expires));
+        if got != *expected {
+            failures.push(format!("{:?} from {}: expected {:?}, got {:?}", value, url, expected, got));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Set-Cookie parsing failures:\n{}", failures.join("\n"));
+    }
+}
+
+#[test]
+fn test_cookies_txt_round_trip() {
+    let lines = [
+        ".instagram.com\tTRUE\t/\tTRUE\t0\tsessionid\tabc%3A123",
+        "www.youtube.com\tFALSE\t/\tFALSE\t1792567680\tSOCS\tCAI",
+    ];
+    for line in lines {
+        let cookie = parse_cookies_txt_line(line).unwrap();
+        assert_eq!(format_cookies_txt_line(&cookie), line);
+    }
+
+    let http_only = parse_cookies_txt_line("#HttpOnly_.tiktok.com\tTRUE\t/\tTRUE\t0\tsid_tt\txyz").unwrap();
+    assert_eq!((http_only.domain.as_str(), http_only.name.as_str()), ("tiktok.com", "sid_tt"));
+    assert_eq!(parse_cookies_txt_line("# Netscape HTTP Cookie File"), None);
+    assert_eq!(parse_cookies_txt_line("too\tfew\tfields"), None);
+
+    let path = std::env::temp_dir().join(format!("chexx0r-jar-{}.txt", std::process::id()));
+    let jar = CookieJar::load(&path).unwrap();
+    assert!(jar.cookies().is_empty(), "a missing jar file starts empty");
+    jar.insert(Cookie::new("instagram.com", "sessionid", "abc"));
+    jar.insert(Cookie { expires: 1, ..Cookie::new("instagram.com", "stale", "x") });
+    jar.save().unwrap();
+
+    let reloaded = CookieJar::load(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(reloaded.cookies(), [Cookie::new("instagram.com", "sessionid", "abc")]);
+}
+
+/// The Cookie header each request carried
+fn cookie_headers(transport: &FakeTransport) -> Vec<Option<String>> {
+    transport.sent().iter()
+        .map(|r| r.headers.iter().find(|(n, _)| n == "Cookie").map(|(_, v)| v.clone()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_cookie_transport() {
+    let inner = FakeTransport::new(&[], HttpResponse {
+        status: 200,
+        headers: vec![
+            header("Set-Cookie", "csrftoken=t0k; Domain=instagram.com; Path=/"),
+            header("set-cookie", "gone=1; Max-Age=0"),
+        ],
+        ..HttpResponse::default()
+    });
+    let jar = CookieJar::new();
+    jar.insert(Cookie::new("instagram.com", "sessionid", "abc"));
+    jar.insert(Cookie { include_subdomains: false, ..Cookie::new("www.instagram.com", "gone", "old") });
+    jar.insert(Cookie::new("tiktok.com", "sid_tt", "xyz"));
+    let transport = jar.wrap(&inner);
+
+    let request = HttpRequest {
+        method: "GET".to_string(),
+        url: "https://www.instagram.com/nike".to_string(),
+        headers: vec![header("Cookie", "own=1")],
+        ..HttpRequest::default()
+    };
+    transport.send(&request).await.unwrap();
+    transport.send(&request).await.unwrap();
+
+    assert_eq!(cookie_headers(&inner), [
+        Some("own=1; sessionid=abc; gone=old".to_string()),
+        Some("own=1; sessionid=abc; csrftoken=t0k".to_string()),
+    ], "second request carries the stored cookie, namesake deleted");
+}
+
+#[tokio::test]
+async fn test_cookies_follow_redirect_hops() {
+    // The consent page sets its cookie on a redirect, not on the page we land on
+    let consent = "https://consent.youtube.com/m?continue=https://www.youtube.com/@chexx0r";
+    let inner = FakeTransport::new(&[
+        ("https://www.youtube.com/@chexx0r", HttpResponse {
+            status: 302,
+            headers: vec![header("Location", consent)],
+            ..HttpResponse::default()
+        }),
+        (consent, HttpResponse {
+            status: 303,
+            headers: vec![
+                header("Location", "https://www.youtube.com/@chexx0r?ucbcb=1"),
+                header("Set-Cookie", "SOCS=CAI; Domain=.youtube.com; Path=/"),
+            ],
+            ..HttpResponse::default()
+        }),
+    ], response(200, "channel"));
+    let jar = CookieJar::new();
+    let transport = jar.wrap(&inner);
+
+    let request = HttpRequest {
+        method: "GET".to_string(),
+        url: "https://www.youtube.com/@chexx0r".to_string(),
+        headers: vec![header("Cookie", "own=1")],
+        follow_redirects: true,
+        ..HttpRequest::default()
+    };
+    let response = transport.send(&request).await.unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.redirects, [consent, "https://www.youtube.com/@chexx0r?ucbcb=1"]);
+    assert!(inner.sent().iter().all(|r| !r.follow_redirects), "the inner transport sends single hops");
+    // The lookup's own cookie stays on its host; the consent cookie reaches the last hop
+    assert_eq!(cookie_headers(&inner), [Some("own=1".to_string()), None, Some("SOCS=CAI".to_string())]);
+    assert_eq!(jar.cookies(), [Cookie::new("youtube.com", "SOCS", "CAI")]);
+}
+
+#[test]
+fn test_header_profile() {
+    let profile = HeaderProfile::from_toml(r#"
+        user_agents = ["agent-a", "agent-b"]
+
+        [sets.browser]
+        Accept = "text/html"
+        Accept-Language = "en"
+    "#).unwrap();
+
+    let own = BTreeMap::from([("accept".to_string(), "application/json".to_string())]);
+    let headers = profile.headers_for(Some("browser"), &own).unwrap();
+    let value = |name: &str| headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str());
+    assert_eq!(headers.iter().filter(|(n, _)| n.eq_ignore_ascii_case("accept")).count(), 1);
+    assert_eq!(value("accept"), Some("application/json"), "a platform's own headers win");
+    assert_eq!(value("accept-language"), Some("en"));
+    assert!(value("user-agent").is_some_and(|ua| ua == "agent-a" || ua == "agent-b"));
+
+    let own_agent = BTreeMap::from([("User-Agent".to_string(), "mine".to_string())]);
+    let headers = profile.headers_for(None, &own_agent).unwrap();
+    assert_eq!(headers, [("User-Agent".to_string(), "mine".to_string())]);
+
+    assert!(profile.headers_for(Some("missing"), &BTreeMap::new()).is_err());
+
+    let bundled = load_header_profile(None).unwrap();
+    assert!(!bundled.user_agents.is_empty());
+    let mut missing = Vec::new();
+    for platform in bundled_platforms() {
+        for set in platform.lookups().filter_map(|probe| probe.header_set.as_deref()) {
+            if !bundled.sets.contains_key(set) {
+                missing.push(format!("{}: {}", platform.name, set));
+            }
+        }
+    }
+    if !missing.is_empty() {
+        panic!("Platforms use header sets that aren't bundled:\n{}", missing.join("\n"));
+    }
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use chexx0r::*;
+use chexx0r::config::Addressing;
+use chexx0r::social::fill_subdomain_template;
+use common::find_bundled;
+
+
+// Subdomain URLs: (username, expected URL, None when it can't be a DNS label)
+const SUBDOMAIN_CASES: &[(&str, Option<&str>)] = &[
+    ("chexx0r", Some("https://chexx0r.substack.com")),
+    ("ChexX0r", Some("https://chexx0r.substack.com")),      // DNS is case-insensitive
+    ("chex-x0r", Some("https://chex-x0r.substack.com")),
+    ("-chexx0r", None),                                      // Labels can't start or end with a hyphen
+    ("chexx0r-", None),
+    ("chex_x0r", None),                                      // No underscores in hostnames
+    ("chex.x0r", None),                                      // One label only
+    ("evil.com/x", None),
+    ("ñandú", None),
+];
+
+#[test]
+fn test_subdomain_templates() {
+    let mut failures = Vec::new();
+
+    for (username, expected) in SUBDOMAIN_CASES {
+        let url = fill_subdomain_template("https://{}.substack.com", username);
+        if url.as_deref() != *expected {
+            failures.push(format!("✗ {}: expected {:?}, got {:?}", username, expected, url));
+        }
+    }
+
+    let too_long = "a".repeat(64);
+    if fill_subdomain_template("https://{}.substack.com", &too_long).is_some() {
+        failures.push("✗ 64-char label accepted".to_string());
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} subdomain failures:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+#[test]
+fn test_creator_definitions() {
+    for name in ["substack", "bandcamp", "tumblr"] {
+        let def = find_bundled(name);
+        assert_eq!(def.probe.addressing, Addressing::Subdomain, "{} should be subdomain-addressed", name);
+        assert_eq!(def.probe.unresolved, Verdict::Available);
+    }
+    for name in ["ko-fi", "patreon", "medium", "linktree"] {
+        assert_eq!(find_bundled(name).probe.addressing, Addressing::Path, "{} should be path-addressed", name);
+    }
+
+    let bandcamp = find_bundled("bandcamp");
+    assert!(!bandcamp.probe.follow_redirects);
+    assert_eq!(evaluate_status(&bandcamp.probe, 302), Verdict::Available);
+    assert_eq!(evaluate_status(&bandcamp.probe, 200), Verdict::Taken);
+
+    let substack = find_bundled("substack");
+    assert_eq!(evaluate_body(&substack.probe, "chexx0r", "<title>Page not found - Substack</title>").verdict, Verdict::Available);
+    assert_eq!(evaluate_body(&substack.probe, "chexx0r", r#"{"subdomain":"chexx0r"}"#).verdict, Verdict::Taken);
+
+    assert_eq!(evaluate_status(&find_bundled("patreon").probe, 403), Verdict::Blocked);
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use chexx0r::debug::{write_captures, RequestCapture};
+use chexx0r::social::trace_rules;
+use common::find_bundled;
+
+#[test]
+fn test_trace_rules_reports_every_rule() {
+    let instagram = find_bundled("instagram");
+    let body = "<title>Nike (@nike) • Instagram photos and videos</title><script>ProfilePage</script>";
+    let page = &instagram.fallbacks[0];
+    let trace = trace_rules(page, "nike", body);
+
+    let matched: Vec<&str> = trace.iter().filter(|(_, m)| *m).map(|(name, _)| name.as_str()).collect();
+    assert_eq!(trace.len(), page.rules.len(), "every rule is traced, not just the first match");
+    assert_eq!(matched, ["title-has-username", "profilepage"]);
+}
+
+#[test]
+fn test_captures_written_per_request() {
+    let dir = std::env::temp_dir().join(format!("chexx0r-debug-{}", std::process::id()));
+    let mut captures = vec![
+        RequestCapture {
+            method: "GET".to_string(),
+            url: "https://social.example/ghost".to_string(),
+            redirects: vec!["https://social.example/login?next=ghost".to_string()],
+            status: Some(200),
+            headers: vec![("content-type".to_string(), "text/html".to_string())],
+            body: Some("<title>Login</title>".to_string()),
+            ..RequestCapture::default()
+        },
+        RequestCapture {
+            method: "GET".to_string(),
+            url: "https://api.social.example/ghost".to_string(),
+            error: Some("timed out".to_string()),
+            ..RequestCapture::default()
+        },
+    ];
+
+    write_captures(&dir, "mastodon:social.example", &mut captures).unwrap();
+    let first = std::fs::read_to_string(dir.join("mastodon_social.example-1.txt")).unwrap();
+    let second = std::fs::read_to_string(dir.join("mastodon_social.example-2.txt")).unwrap();
+    std::fs::remove_dir_all(&dir).ok();
+
+    assert_eq!(first, "GET https://social.example/ghost\n-> https://social.example/login?next=ghost\nstatus: 200\ncontent-type: text/html\n\n<title>Login</title>");
+    assert!(second.contains("error: timed out"));
+    assert!(captures.iter().all(|c| c.saved_to.is_some()));
+}
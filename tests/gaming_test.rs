@@ -0,0 +1,90 @@
+mod common;
+
+use chexx0r::*;
+use chexx0r::config::get_platform_preset;
+use common::find_bundled;
+
+
+#[test]
+fn test_platform_presets() {
+    assert_eq!(get_platform_preset("gaming"), ["twitch", "kick", "steam", "roblox"]);
+    assert_eq!(get_platform_preset("YouTube, twitch"), ["youtube", "twitch"]);
+
+    // Every preset entry must name a bundled platform
+    let bundled = bundled_platforms();
+    for name in get_platform_preset("all") {
+        assert!(bundled.iter().any(|p| p.name == name), "preset names unknown platform {}", name);
+    }
+}
+
+// Gaming usernames: (username, platforms where it must be invalid)
+const GAMING_USERNAMES: &[(&str, &[&str])] = &[
+    ("chexx0r", &[]),
+    ("abc", &["Twitch", "Kick"]),                              // Twitch and Kick need 4+ chars
+    ("ab", &["Twitch", "Kick", "Steam", "Roblox"]),
+    ("_chexx0r", &["Twitch", "Kick", "Roblox"]),               // Leading underscore
+    ("chex_x0r_", &["Roblox"]),                                // Trailing underscore
+    ("chex_x_0r", &["Roblox"]),                                // Roblox allows one underscore
+    ("chex-x0r", &["Twitch", "Kick", "Roblox"]),               // Hyphens only in Steam URLs
+    ("chexx0r_official_gaming", &["Roblox"]),                  // 23 chars > 20
+    ("ñandú", &["Twitch", "Kick", "Steam", "Roblox"]),         // ASCII only
+];
+
+type Validator = fn(&str) -> Result<(), ValidationError>;
+
+#[test]
+fn test_gaming_usernames() {
+    let validators: &[(&str, Validator)] = &[
+        ("Twitch", validate_twitch_username),
+        ("Kick", validate_kick_username),
+        ("Steam", validate_steam_username),
+        ("Roblox", validate_roblox_username),
+    ];
+    let mut failures = Vec::new();
+
+    for (username, invalid_platforms) in GAMING_USERNAMES {
+        for (platform, validate) in validators {
+            let should_be_invalid = invalid_platforms.contains(platform);
+            if validate(username).is_ok() == should_be_invalid {
+                failures.push(format!("✗ {}-{}: expected {}", platform, username, if should_be_invalid { "INVALID" } else { "VALID" }));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} validation failures:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+// Gaming lookup bodies: (platform, body, expected verdict, expected label)
+const GAMING_BODIES: &[(&str, &str, Verdict, Option<&str>)] = &[
+    ("twitch", r#"{"data":{"user":{"id":"12826"}}}"#, Verdict::Taken, None),
+    ("twitch", r#"{"data":{"user":null}}"#, Verdict::Available, None),
+    ("steam", "<script>g_rgProfileData = {\"url\":\"https://steamcommunity.com/id/gaben/\"}</script>", Verdict::Taken, None),
+    ("steam", "<h3>The specified profile could not be found.</h3>", Verdict::Available, None),
+    ("roblox", r#"{"code":0,"message":"Username is valid"}"#, Verdict::Available, None),
+    ("roblox", r#"{"code":1,"message":"Username is already in use"}"#, Verdict::Taken, None),
+    ("roblox", r#"{"code":2,"message":"Username not appropriate for Roblox"}"#, Verdict::Invalid, Some("inappropriate")),
+    ("roblox", r#"{"code":7,"message":"Only a-z, A-Z, 0-9, and _ are allowed."}"#, Verdict::Invalid, None),
+];
+
+#[test]
+fn test_gaming_lookups() {
+    let mut failures = Vec::new();
+
+    for (platform, body, verdict, label) in GAMING_BODIES {
+        let detection = evaluate_body(&find_bundled(platform).probe, "", body);
+        if detection.verdict != *verdict || detection.label.as_deref() != *label {
+            failures.push(format!("✗ {}: expected {:?}/{:?}, got {:?}", platform, verdict, label, detection));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} rule mismatches:\n{}", failures.len(), failures.join("\n"));
+    }
+
+    let kick = find_bundled("kick");
+    assert_eq!(evaluate_status(&kick.probe, 200), Verdict::Taken);
+    assert_eq!(evaluate_status(&kick.probe, 404), Verdict::Available);
+    assert_eq!(evaluate_status(&kick.probe, 403), Verdict::Blocked);
+}
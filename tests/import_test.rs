@@ -0,0 +1,139 @@
+use chexx0r::*;
+use chexx0r::import::{detect_format, import_sherlock, import_whatsmyname, merge_imported, ImportOptions, SiteDatabase, IMPORTED_GROUP};
+
+const SHERLOCK_SAMPLE: &str = r#"{
+    "$schema": "data.schema.json",
+    "GitHub": {
+        "errorType": "status_code",
+        "regexCheck": "^[a-zA-Z0-9](?:[a-zA-Z0-9]|-(?=[a-zA-Z0-9])){0,38}$",
+        "url": "https://www.github.com/{}",
+        "urlMain": "https://www.github.com/",
+        "username_claimed": "blue"
+    },
+    "Hacker News": {
+        "errorMsg": ["No such user.", "User not found"],
+        "errorType": "message",
+        "url": "https://news.ycombinator.com/user?id={}",
+        "username_claimed": "blue"
+    },
+    "Ello": {
+        "errorType": "response_url",
+        "errorUrl": "https://ello.co/",
+        "url": "https://ello.co/{}",
+        "username_claimed": "blue"
+    },
+    "YouTube": {
+        "errorType": "status_code",
+        "url": "https://www.youtube.com/@{}",
+        "username_claimed": "youtube"
+    },
+    "Adult Site": {
+        "errorType": "status_code",
+        "isNSFW": true,
+        "url": "https://adult.example.com/{}",
+        "username_claimed": "blue"
+    }
+}"#;
+
+const WMN_SAMPLE: &str = r#"{
+    "license": ["CC BY-SA 4.0"],
+    "categories": ["social"],
+    "sites": [
+        {
+            "name": "Example Forum",
+            "uri_check": "https://forum.example.com/u/{account}.json",
+            "e_code": 200,
+            "e_string": "\"username\":",
+            "m_code": 404,
+            "m_string": "not found",
+            "known": ["alice"],
+            "cat": "social"
+        },
+        {
+            "name": "Broken Site",
+            "uri_check": "https://broken.example.com/{account}",
+            "e_code": 200,
+            "e_string": "profile",
+            "m_code": 404,
+            "m_string": "missing",
+            "valid": false,
+            "cat": "social"
+        }
+    ]
+}"#;
+
+#[test]
+fn test_sherlock_import() {
+    let json = serde_json::from_str(SHERLOCK_SAMPLE).unwrap();
+    assert_eq!(detect_format(&json), Some(SiteDatabase::Sherlock));
+
+    let platforms = import_sherlock(&json, ImportOptions::default()).unwrap();
+    let names: Vec<&str> = platforms.iter().map(|p| p.name.as_str()).collect();
+    assert!(names.contains(&"github") && names.contains(&"hacker-news") && names.contains(&"ello"));
+    assert!(!names.contains(&"adult-site"), "NSFW sites should be skipped by default");
+
+    let github = platforms.iter().find(|p| p.name == "github").unwrap();
+    assert_eq!(evaluate_status(&github.probe, 200), Verdict::Taken);
+    assert_eq!(evaluate_status(&github.probe, 404), Verdict::Available);
+    let rules = github.validation.as_ref().unwrap();
+    assert!(validate_username("blue-team", rules).is_ok());
+    assert!(validate_username("-blue", rules).is_err());
+
+    let hn = platforms.iter().find(|p| p.name == "hacker-news").unwrap();
+    assert_eq!(evaluate_status(&hn.probe, 200), Verdict::Inspect);
+    assert_eq!(evaluate_body(&hn.probe, "ghost", "No such user.").verdict, Verdict::Available);
+    assert_eq!(evaluate_body(&hn.probe, "blue", "<html>user: blue</html>").verdict, Verdict::Taken);
+
+    let ello = platforms.iter().find(|p| p.name == "ello").unwrap();
+    assert!(!ello.probe.follow_redirects);
+    assert_eq!(evaluate_status(&ello.probe, 302), Verdict::Available);
+}
+
+#[test]
+fn test_whatsmyname_import() {
+    let json = serde_json::from_str(WMN_SAMPLE).unwrap();
+    assert_eq!(detect_format(&json), Some(SiteDatabase::WhatsMyName));
+
+    let platforms = import_whatsmyname(&json, ImportOptions::default()).unwrap();
+    assert_eq!(platforms.len(), 1, "invalid sites should be skipped");
+
+    let forum = &platforms[0];
+    assert_eq!(forum.name, "example-forum");
+    assert_eq!(forum.probe.url_template, "https://forum.example.com/u/{}.json");
+    assert_eq!(evaluate_status(&forum.probe, 200), Verdict::Inspect);
+    assert_eq!(evaluate_body(&forum.probe, "alice", r#"{"username":"alice"}"#).verdict, Verdict::Taken);
+    assert_eq!(evaluate_body(&forum.probe, "ghost", "user not found").verdict, Verdict::Available);
+}
+
+#[test]
+fn test_curated_platforms_win() {
+    let json = serde_json::from_str(SHERLOCK_SAMPLE).unwrap();
+    let mut platforms = bundled_platforms();
+    merge_imported(&mut platforms, import_sherlock(&json, ImportOptions::default()).unwrap());
+
+    let youtube: Vec<&SocialPlatform> = platforms.iter().filter(|p| p.name == "youtube").collect();
+    assert_eq!(youtube.len(), 1);
+    assert_eq!(youtube[0].source, None, "curated YouTube definition should be kept");
+}
+
+#[test]
+fn test_presets_keep_imported_sites() {
+    let sherlock = serde_json::from_str(SHERLOCK_SAMPLE).unwrap();
+    let wmn = serde_json::from_str(WMN_SAMPLE).unwrap();
+    let mut imported = import_sherlock(&sherlock, ImportOptions::default()).unwrap();
+    imported.extend(import_whatsmyname(&wmn, ImportOptions::default()).unwrap());
+    assert!(imported.iter().all(|p| p.group == IMPORTED_GROUP));
+
+    let mut platforms = bundled_platforms();
+    merge_imported(&mut platforms, imported);
+    let selection = PlatformSelection {
+        platforms: Some("social".to_string()),
+        skip_code: true,
+        ..Default::default()
+    };
+    selection.retain(&mut platforms);
+
+    let names: Vec<&str> = platforms.iter().map(|p| p.name.as_str()).collect();
+    assert!(names.contains(&"hacker-news") && names.contains(&"example-forum"), "imported sites should survive a preset: {:?}", names);
+    assert!(names.contains(&"youtube") && !names.contains(&"twitch") && !names.contains(&"gitlab"));
+}
@@ -1,5 +1,5 @@
 use chexx0r::*;
-use std::collections::HashMap;
+use chexx0r::social::check_social_media;
 
 // Known taken usernames (verified to exist on platforms)
 const KNOWN_TAKEN: &[&str] = &[
@@ -39,9 +39,9 @@ const INVALID_USERNAMES: &[(&str, &[&str])] = &[
     ("user_name_", &["YouTube"]),       // Ends with underscore (invalid for YouTube)
     ("-username", &["YouTube", "TikTok", "Instagram"]), // Starts with hyphen (invalid for YouTube/TikTok, TikTok needs letter start), contains hyphen (invalid for Instagram)
     ("_username", &["YouTube", "TikTok"]), // Starts with underscore (invalid for YouTube/TikTok, TikTok needs letter start)
-    (".username", &["Instagram", "TikTok", "YouTube"]), // Starts with period (invalid for Instagram/TikTok, TikTok needs letter start), contains period (invalid for YouTube - only allows letters, numbers, hyphens, underscores)
-    ("username.", &["Instagram", "YouTube"]), // Ends with period (invalid for Instagram), contains period (invalid for YouTube)
-    ("user..name", &["Instagram", "YouTube"]), // Consecutive periods (invalid for Instagram), contains period (invalid for YouTube)
+    (".username", &["Instagram", "TikTok"]), // Starts with period (invalid for Instagram/TikTok, TikTok needs letter start), YouTube handles allow periods
+    ("username.", &["Instagram", "TikTok"]), // Ends with period (invalid for Instagram/TikTok)
+    ("user..name", &["Instagram"]), // Consecutive periods (invalid for Instagram)
     ("user__name", &["TikTok"]),       // Consecutive underscores (invalid for TikTok)
     ("1username", &["TikTok"]),        // Starts with number (invalid for TikTok)
     ("user!name", &["YouTube", "Instagram", "TikTok"]), // Invalid char (!) for all platforms
@@ -53,12 +53,11 @@ const INVALID_USERNAMES: &[(&str, &[&str])] = &[
     ("user+name", &["YouTube", "Instagram", "TikTok"]), // Invalid char (+) for all platforms
     // Edge cases: Empty and boundary lengths
     ("", &["YouTube", "Instagram", "TikTok"]), // Empty string (invalid for all)
-    ("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", &["YouTube", "Instagram", "TikTok"]), // Too long for all (31 chars > 30/20/24)
-    ("aaaaaaaaaaaaaaaaaaaaa", &["YouTube"]), // Too long for YouTube (21 chars > 20), but valid for Instagram/TikTok
-    ("aaaaaaaaaaaaaaaaaaaaaaaaa", &["YouTube", "TikTok"]), // Too long for TikTok (25 chars > 24), and YouTube (25 > 20), but valid for Instagram
+    ("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", &["YouTube", "Instagram", "TikTok"]), // Too long for all (31 chars > 30/30/24)
+    ("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", &["TikTok"]), // Exactly 30 chars (maximum for YouTube/Instagram, too long for TikTok)
+    ("aaaaaaaaaaaaaaaaaaaaaaaaa", &["TikTok"]), // Too long for TikTok (25 chars > 24), but valid for YouTube/Instagram
     // Edge cases: Unicode and special characters
-    // Note: Current validation uses is_alphanumeric() which accepts Unicode letters like "ñ"
-    // This might be a limitation - platforms may not actually allow Unicode
+    ("ñandú", &["Instagram", "TikTok"]), // Instagram and TikTok are ASCII only, YouTube handles take letters from many scripts
     ("user🚀name", &["YouTube", "Instagram", "TikTok"]), // Emoji (invalid for all)
     ("user\tname", &["YouTube", "Instagram", "TikTok"]), // Tab character (invalid for all)
     ("user\nname", &["YouTube", "Instagram", "TikTok"]), // Newline (invalid for all)
@@ -68,90 +67,47 @@ const INVALID_USERNAMES: &[(&str, &[&str])] = &[
     ("ab", &["YouTube"]), // Exactly 2 chars (valid for Instagram/TikTok, but invalid for YouTube - min 3)
 ];
 
+// Live lookups through the bundled definitions; sites change and rate-limit, so these only run on demand
+// (`cargo test -- --ignored`). Offline regressions replay tests/cassettes in replay_test.rs
+fn live_platforms() -> Vec<SocialPlatform> {
+    bundled_platforms().into_iter().filter(|p| ["youtube", "instagram", "tiktok"].contains(&p.name.as_str())).collect()
+}
+
 #[tokio::test]
+#[ignore = "live network"]
 async fn test_known_taken_usernames() {
-    let mut results = HashMap::new();
-    
-    for username in KNOWN_TAKEN {
-        println!("\nTesting KNOWN TAKEN: {}", username);
-        
-        // Test YouTube
-        let youtube_url = format!("https://www.youtube.com/@{}", username);
-        let youtube_result = check_social_platform_internal(&youtube_url, "YouTube").await;
-        results.insert(format!("YouTube-{}", username), youtube_result);
-        
-        // Test Instagram
-        let instagram_url = format!("https://www.instagram.com/{}", username);
-        let instagram_result = check_social_platform_internal(&instagram_url, "Instagram").await;
-        results.insert(format!("Instagram-{}", username), instagram_result);
-        
-        // Test TikTok
-        let tiktok_url = format!("https://www.tiktok.com/@{}", username);
-        let tiktok_result = check_social_platform_internal(&tiktok_url, "TikTok").await;
-        results.insert(format!("TikTok-{}", username), tiktok_result);
-    }
-    
-    // Check results
+    let transport = ReqwestTransport::new().unwrap();
+    let platforms = live_platforms();
     let mut failures = Vec::new();
-    for (key, result) in &results {
-        match result {
-            Ok(true) => {
-                println!("✓ {}: CORRECTLY detected as TAKEN", key);
-            }
-            Ok(false) => {
-                failures.push(format!("✗ {}: FALSE NEGATIVE - should be TAKEN but detected as AVAILABLE", key));
-            }
-            Err(e) => {
-                failures.push(format!("✗ {}: ERROR - {}", key, e));
+
+    for username in KNOWN_TAKEN {
+        for result in check_social_media(username, &platforms, &transport, CheckOptions::default()).await.unwrap() {
+            if result.status != SocialStatus::Taken {
+                failures.push(format!("✗ {}-{}: expected TAKEN, got {:?} by {}", result.platform, username, result.status, result.rule));
             }
         }
     }
-    
+
     if !failures.is_empty() {
         panic!("Found {} false negatives:\n{}", failures.len(), failures.join("\n"));
     }
 }
 
 #[tokio::test]
+#[ignore = "live network"]
 async fn test_known_untaken_usernames() {
-    let mut results = HashMap::new();
-    
-    for username in KNOWN_UNTAKEN {
-        println!("\nTesting KNOWN UNTAKEN: {}", username);
-        
-        // Test YouTube
-        let youtube_url = format!("https://www.youtube.com/@{}", username);
-        let youtube_result = check_social_platform_internal(&youtube_url, "YouTube").await;
-        results.insert(format!("YouTube-{}", username), youtube_result);
-        
-        // Test Instagram
-        let instagram_url = format!("https://www.instagram.com/{}", username);
-        let instagram_result = check_social_platform_internal(&instagram_url, "Instagram").await;
-        results.insert(format!("Instagram-{}", username), instagram_result);
-        
-        // Test TikTok
-        let tiktok_url = format!("https://www.tiktok.com/@{}", username);
-        let tiktok_result = check_social_platform_internal(&tiktok_url, "TikTok").await;
-        results.insert(format!("TikTok-{}", username), tiktok_result);
-    }
-    
-    // Check results
+    let transport = ReqwestTransport::new().unwrap();
+    let platforms = live_platforms();
     let mut failures = Vec::new();
-    for (key, result) in &results {
-        match result {
-            Ok(false) => {
-                println!("✓ {}: CORRECTLY detected as AVAILABLE", key);
-            }
-            Ok(true) => {
-                failures.push(format!("✗ {}: FALSE POSITIVE - should be AVAILABLE but detected as TAKEN", key));
-            }
-            Err(e) => {
-                // Errors are acceptable for untaken usernames (network issues, etc.)
-                println!("? {}: ERROR (acceptable) - {}", key, e);
+
+    for username in KNOWN_UNTAKEN {
+        for result in check_social_media(username, &platforms, &transport, CheckOptions::default()).await.unwrap() {
+            if result.status != SocialStatus::Available {
+                failures.push(format!("✗ {}-{}: expected AVAILABLE, got {:?} by {}", result.platform, username, result.status, result.rule));
             }
         }
     }
-    
+
     if !failures.is_empty() {
         panic!("Found {} false positives:\n{}", failures.len(), failures.join("\n"));
     }
@@ -202,61 +158,3 @@ async fn test_invalid_usernames() {
         panic!("Found {} validation failures:\n{}", failures.len(), failures.join("\n"));
     }
 }
-
-// Helper function to check social platform (internal version for testing)
-async fn check_social_platform_internal(url: &str, _platform: &str) -> Result<bool, String> {
-    use reqwest::Client;
-    use std::time::Duration;
-    
-    let client = Client::builder()
-        .user_agent("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36")
-        .timeout(Duration::from_secs(10))
-        .redirect(reqwest::redirect::Policy::limited(5))
-        .build()
-        .map_err(|e| format!("Failed to create client: {}", e))?;
-    
-    let response = client
-        .get(url)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to send request: {}", e))?;
-    
-    let status = response.status();
-    
-    // YouTube: Returns 404 for non-existent profiles, 200 for existing ones
-    if url.contains("youtube.com") {
-        if status.as_u16() == 404 {
-            return Ok(false); // Available
-        } else if status.is_success() {
-            return Ok(true); // Taken
-        } else {
-            return Err(format!("HTTP {}", status.as_u16()));
-        }
-    }
-    
-    // Instagram and TikTok return 200 but show specific error messages
-    if status.as_u16() == 404 {
-        return Ok(false); // Available
-    }
-    
-    if status.is_success() {
-        let body = response.text().await.map_err(|e| format!("Failed to read body: {}", e))?;
-        let body_lower = body.to_lowercase();
-        
-        // Check for platform-specific patterns using the library functions
-        if url.contains("instagram.com") {
-            return check_instagram_availability(&body_lower, url)
-                .map_err(|e| format!("Instagram check error: {}", e));
-        }
-        
-        if url.contains("tiktok.com") {
-            return check_tiktok_availability(&body_lower)
-                .map_err(|e| format!("TikTok check error: {}", e));
-        }
-        
-        // Default: assume taken
-        Ok(true)
-    } else {
-        Err(format!("HTTP {}", status.as_u16()))
-    }
-}
@@ -0,0 +1,507 @@
+mod common;
+
+use chexx0r::*;
+use chexx0r::config::parse_platforms;
+use chexx0r::social::{embedded_json, redirect_wall};
+use chexx0r::utils::validate_platform_username;
+use common::find_bundled;
+
+
+// Body cases: (platform, username, body, expected verdict)
+const BODY_CASES: &[(&str, &str, &str, Verdict)] = &[
+    ("instagram", "nike", r#"{"data":{"user":{"id":"13460080","username":"nike","is_private":false}},"status":"ok"}"#, Verdict::Taken),
+    ("instagram", "secret", r#"{"data":{"user":{"id":"1","username":"secret","is_private":true}},"status":"ok"}"#, Verdict::Taken),
+    ("instagram", "adult", r#"{"data":{"user":null},"status":"ok"}"#, Verdict::Taken),    // Restricted
+    ("instagram", "ghost", r#"{"require_login":true,"status":"fail"}"#, Verdict::Next),  // Page decides
+    ("instagram", "ghost", "<title>Login • Instagram</title>", Verdict::Next),
+    ("tiktok", "ghost", r#"<div id="tiktok-verify-page">Verify to continue</div>"#, Verdict::Blocked), // Captcha
+    ("tiktok", "ghost", "<html></html>", Verdict::Unknown),
+];
+
+#[test]
+fn test_bundled_body_rules() {
+    let mut failures = Vec::new();
+
+    for (platform, username, body, expected) in BODY_CASES {
+        let verdict = evaluate_body(&find_bundled(platform).probe, username, body).verdict;
+        if verdict != *expected {
+            failures.push(format!("✗ {}-{}: expected {:?}, got {:?}", platform, username, expected, verdict));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} rule mismatches:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+// Instagram's profile page, checked when the JSON API doesn't answer
+const INSTAGRAM_PAGE_CASES: &[(&str, &str, Verdict)] = &[
+    ("nike", "<title>Nike (@nike) • Instagram photos and videos</title>", Verdict::Taken),
+    ("nike", "<title>Nike (&#064;nike) • Instagram photos and videos</title>", Verdict::Taken),
+    ("ghost", "<title>Instagram</title>", Verdict::Available),
+    ("ghost", "<title>Login • Instagram</title>", Verdict::Blocked),   // Login wall
+    ("ghost", "<html>nothing to see</html>", Verdict::Unknown),
+];
+
+#[test]
+fn test_instagram_page_rules() {
+    let instagram = find_bundled("instagram");
+    let page = &instagram.fallbacks[0];
+    assert_eq!(page.url_template, "https://www.instagram.com/{}");
+
+    for (username, body, expected) in INSTAGRAM_PAGE_CASES {
+        let detection = evaluate_body(page, username, body);
+        assert_eq!(detection.verdict, *expected, "{} with {}", username, body);
+    }
+
+    let labels: Vec<Option<String>> = [
+        r#"{"data":{"user":{"id":"1","is_private":true}}}"#,
+        r#"{"data":{"user":{"id":"1","is_private":false}}}"#,
+        r#"{"data":{"user":null}}"#,
+    ]
+    .iter()
+    .map(|body| evaluate_body(&instagram.probe, "nike", body).label)
+    .collect();
+    assert_eq!(labels, [Some("private".to_string()), None, Some("restricted".to_string())]);
+}
+
+/// A TikTok profile page with `detail` as its webapp.user-detail data
+fn tiktok_page(detail: &str) -> String {
+    format!(
+        r#"<html><script id="__UNIVERSAL_DATA_FOR_REHYDRATION__" type="application/json">{{"__DEFAULT_SCOPE__":{{"webapp.user-detail":{}}}}}</script></html>"#,
+        detail
+    )
+}
+
+// TikTok's embedded JSON: (username, webapp.user-detail, expected verdict, expected label)
+const TIKTOK_CASES: &[(&str, &str, Verdict, Option<&str>)] = &[
+    ("nike", r#"{"userInfo":{"user":{"uniqueId":"nike"}},"statusCode":0}"#, Verdict::Taken, None),
+    ("Nike", r#"{"userInfo":{"user":{"uniqueId":"nike"}},"statusCode":0}"#, Verdict::Taken, None),  // Case-insensitive
+    ("nike", r#"{"userInfo":{"user":{"uniqueId":"nikestore"}},"statusCode":0}"#, Verdict::Unknown, Some("page is @nikestore")),
+    ("ghost", r#"{"statusCode":10202,"statusMsg":"user not exist"}"#, Verdict::Available, None),
+    ("spammer", r#"{"statusCode":10221,"statusMsg":"user banned"}"#, Verdict::Suspended, None),
+    ("shy", r#"{"userInfo":{"user":{"uniqueId":"shy"}},"statusCode":10222}"#, Verdict::Taken, Some("private")),
+    ("ghost", r#"{"statusCode":10223,"statusMsg":""}"#, Verdict::Unknown, Some("status code 10223")),  // Never guessed available
+    // The ids only appear as values elsewhere: no typed field matches
+    ("ghost", r#"{"statusMsg":""uniqueId":"ghost","statusCode":0"}"#, Verdict::Unknown, None),
+];
+
+#[test]
+fn test_tiktok_embedded_json() {
+    let tiktok = find_bundled("tiktok");
+    let mut failures = Vec::new();
+
+    for (username, detail, verdict, label) in TIKTOK_CASES {
+        let detection = evaluate_body(&tiktok.probe, username, &tiktok_page(detail));
+        if detection.verdict != *verdict || detection.label.as_deref() != *label {
+            failures.push(format!(
+                "✗ {} with {}: expected {:?} {:?}, got {:?} {:?} by {}",
+                username, detail, verdict, label, detection.verdict, detection.label, detection.rule
+            ));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} TikTok mismatches:\n{}", failures.len(), failures.join("\n"));
+    }
+
+    // Older pages keep the same fields in SIGI_STATE
+    let sigi = r#"<script id='SIGI_STATE'>{"UserPage":{"uniqueId":"Nike","statusCode":0}}</script>"#;
+    assert_eq!(evaluate_body(&tiktok.probe, "nike", sigi).rule, "sigi-profile");
+    assert!(embedded_json("<script id=\"SIGI_STATE\">not json</script>", "SIGI_STATE").is_none());
+    assert!(check_tiktok_availability(&tiktok_page(r#"{"statusCode":10223}"#), "https://www.tiktok.com/@ghost").is_err());
+    assert!(check_tiktok_availability(&tiktok_page(r#"{"statusCode":10202}"#), "https://www.tiktok.com/@ghost").is_ok_and(|taken| !taken));
+}
+
+#[test]
+fn test_status_table() {
+    let youtube = find_bundled("youtube");
+    assert_eq!(evaluate_status(&youtube.probe, 200), Verdict::Taken);
+    assert_eq!(evaluate_status(&youtube.probe, 404), Verdict::Available);
+    assert_eq!(evaluate_status(&youtube.probe, 500), Verdict::Unknown);
+
+    let instagram = find_bundled("instagram");
+    assert_eq!(evaluate_status(&instagram.probe, 200), Verdict::Inspect);
+    assert_eq!(evaluate_status(&instagram.probe, 404), Verdict::Available);
+    assert_eq!(evaluate_status(&instagram.probe, 429), Verdict::RateLimited);
+    assert_eq!(evaluate_status(&instagram.probe, 401), Verdict::Next);
+}
+
+#[test]
+fn test_wall_redirects() {
+    let consent = "https://consent.youtube.com/m?continue=https%3A%2F%2Fwww.youtube.com%2F%40ghost".to_string();
+    for name in ["youtube", "youtube:c", "youtube:user"] {
+        let wall = redirect_wall(&find_bundled(name).probe, std::slice::from_ref(&consent));
+        assert_eq!(wall.map(|d| (d.verdict, d.label)), Some((Verdict::Blocked, Some("redirected to consent.youtube.com".to_string()))), "{}", name);
+    }
+
+    let handle_redirect = ["https://www.youtube.com/@Ghost".to_string()];
+    assert!(redirect_wall(&find_bundled("youtube").probe, &handle_redirect).is_none());
+    assert!(redirect_wall(&find_bundled("tiktok").probe, std::slice::from_ref(&consent)).is_none(), "only listed walls count");
+}
+
+#[test]
+fn test_only_available_counts_as_available() {
+    let verdicts = [
+        Verdict::Available, Verdict::Taken, Verdict::Suspended, Verdict::Reserved, Verdict::Invalid,
+        Verdict::Blocked, Verdict::RateLimited, Verdict::Unknown, Verdict::Inspect, Verdict::Next,
+    ];
+    for verdict in verdicts {
+        let status = SocialStatus::from_verdict(verdict);
+        assert_eq!(status.is_available(), verdict == Verdict::Available, "{:?} -> {:?}", verdict, status);
+    }
+
+    // Walls and captchas are errors for the legacy helpers, not "not taken"
+    assert!(check_instagram_availability("<title>login • instagram</title>", "https://www.instagram.com/ghost").is_err());
+    assert!(check_tiktok_availability(r#"<div id="tiktok-verify-page"></div>"#, "https://www.tiktok.com/@ghost").is_err());
+    assert!(!check_instagram_availability("<title>instagram</title>", "https://www.instagram.com/ghost").unwrap());
+}
+
+#[test]
+fn test_json_pointer_rules() {
+    let defs = parse_platforms(r#"
+        [[platforms]]
+        name = "example"
+        url_template = "https://api.example.com/users/{}"
+        fallback = "available"
+
+        [[platforms.rules]]
+        name = "org"
+        json = { pointer = "/type", equals = "organization" }
+        verdict = "taken"
+
+        [[platforms.rules]]
+        name = "error"
+        json = { pointer = "/error", exists = true }
+        verdict = "blocked"
+    "#).unwrap();
+    let example = &defs[0].probe;
+
+    assert_eq!(evaluate_body(example, "acme", r#"{"type":"Organization"}"#).verdict, Verdict::Taken);
+    assert_eq!(evaluate_body(example, "acme", r#"{"error":"rate limited"}"#).verdict, Verdict::Blocked);
+    assert_eq!(evaluate_body(example, "acme", r#"{"type":"User"}"#).verdict, Verdict::Available);
+    assert_eq!(evaluate_body(example, "acme", "not json").verdict, Verdict::Available);
+}
+
+#[test]
+fn test_user_definitions_replace_bundled() {
+    let dir = std::env::temp_dir().join(format!("chexx0r-platforms-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("custom.toml"), r#"
+        [[platforms]]
+        name = "youtube"
+        url_template = "https://yt.example.com/@{}"
+
+        [[platforms]]
+        name = "example"
+        url_template = "https://example.com/{}"
+    "#).unwrap();
+
+    let platforms = load_platforms(&[&dir]).unwrap();
+    std::fs::remove_dir_all(&dir).ok();
+
+    let youtube = platforms.iter().find(|p| p.name == "youtube").unwrap();
+    assert_eq!(youtube.probe.url_template, "https://yt.example.com/@{}");
+    assert_eq!(platforms.iter().filter(|p| p.name == "youtube").count(), 1);
+    assert_eq!(platforms.last().unwrap().name, "example");
+}
+
+// Code host API bodies: (platform, lookup index, body, expected verdict, expected label)
+const CODE_HOST_CASES: &[(&str, usize, &str, Verdict, Option<&str>)] = &[
+    ("github", 0, r#"{"login":"rust-lang","type":"Organization"}"#, Verdict::Taken, Some("organization")),
+    ("github", 0, r#"{"login":"octocat","type":"User"}"#, Verdict::Taken, Some("user")),
+    ("gitlab", 0, r#"[{"id":1,"username":"alice"}]"#, Verdict::Taken, Some("user")),
+    ("gitlab", 0, "[]", Verdict::Next, None),
+    ("gitlab", 1, r#"{"id":9970,"name":"GitLab.org"}"#, Verdict::Taken, Some("group")),
+    ("codeberg", 0, r#"{"id":42,"username":"forgejo"}"#, Verdict::Taken, Some("organization")),
+    ("codeberg", 1, r#"{"id":7,"login":"alice"}"#, Verdict::Taken, Some("user")),
+    ("bitbucket", 0, r#"{"type":"workspace","slug":"atlassian"}"#, Verdict::Taken, Some("workspace")),
+];
+
+#[test]
+fn test_code_host_rules() {
+    let mut failures = Vec::new();
+
+    for (platform, index, body, verdict, label) in CODE_HOST_CASES {
+        let def = find_bundled(platform);
+        let probe = std::iter::once(&def.probe).chain(&def.fallbacks).nth(*index).unwrap();
+        let detection = evaluate_body(probe, "", body);
+        if detection.verdict != *verdict || detection.label.as_deref() != *label {
+            failures.push(format!("✗ {}[{}]: expected {:?}/{:?}, got {:?}", platform, index, verdict, label, detection));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} rule mismatches:\n{}", failures.len(), failures.join("\n"));
+    }
+
+    assert_eq!(evaluate_status(&find_bundled("github").probe, 404), Verdict::Available);
+    assert_eq!(evaluate_status(&find_bundled("codeberg").probe, 404), Verdict::Next);
+    assert!(find_bundled("github").reserved.iter().any(|r| r == "settings"));
+}
+
+// Code host usernames: (username, hosts where it must be invalid)
+const CODE_HOST_USERNAMES: &[(&str, &[&str])] = &[
+    ("rust-lang", &[]),
+    ("a", &["GitLab"]),                                      // GitLab needs 2+ chars
+    ("-rust", &["GitHub", "GitLab", "Codeberg"]),            // Leading hyphen
+    ("rust--lang", &["GitHub", "Codeberg"]),                 // Consecutive hyphens
+    ("rust_lang", &["GitHub"]),                              // Underscore not allowed on GitHub
+    ("rust.git", &["GitHub", "GitLab", "Bitbucket"]),        // GitLab forbids ".git" suffix
+    ("RustLang", &["Bitbucket"]),                            // Bitbucket workspace IDs are lowercase
+    ("ñandú", &["GitHub", "GitLab", "Codeberg", "Bitbucket"]), // ASCII only
+    ("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", &["GitHub"]), // 40 chars > 39
+];
+
+type Validator = fn(&str) -> Result<(), ValidationError>;
+
+#[test]
+fn test_code_host_usernames() {
+    let validators: &[(&str, Validator)] = &[
+        ("GitHub", validate_github_username),
+        ("GitLab", validate_gitlab_username),
+        ("Codeberg", validate_codeberg_username),
+        ("Bitbucket", validate_bitbucket_username),
+    ];
+    let mut failures = Vec::new();
+
+    for (username, invalid_hosts) in CODE_HOST_USERNAMES {
+        for (host, validate) in validators {
+            let should_be_invalid = invalid_hosts.contains(host);
+            if validate(username).is_ok() == should_be_invalid {
+                failures.push(format!("✗ {}-{}: expected {}", host, username, if should_be_invalid { "INVALID" } else { "VALID" }));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} validation failures:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+// Why a name is invalid: (validator, username, reasons in rule order)
+const VIOLATION_CASES: &[(&str, &str, &str)] = &[
+    ("instagram", ".chex..x0r!", "can't start with '.', consecutive periods, bad character '!' at position 11"),
+    ("instagram", "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "too long (31 > 30)"),
+    ("youtube", "a!", "too short (2 < 3), bad character '!' at position 2"),
+    ("youtube", "-chexx0r", "can't start with '-'"),
+    ("tiktok", "1chexx0r__", "consecutive underscores, must start with a letter"),
+    ("telegram", "9a_", "too short (3 < 5), can't end with '_', must start with a letter"),
+];
+
+#[test]
+fn test_validation_errors() {
+    let mut failures = Vec::new();
+
+    for (platform, username, expected) in VIOLATION_CASES {
+        let rules = find_bundled(platform).validation.unwrap();
+        let got = validate_username(username, &rules).err().map(|e| e.to_string());
+        if got.as_deref() != Some(*expected) {
+            failures.push(format!("{}-{}: expected {:?}, got {:?}", platform, username, expected, got));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Validation error failures:\n{}", failures.join("\n"));
+    }
+
+    let error = validate_instagram_username("chex x0r").unwrap_err();
+    assert_eq!(error.violations, [Violation::BadCharacter { character: ' ', position: 5 }]);
+}
+
+#[test]
+fn test_unknown_platform_is_an_error() {
+    assert!(validate_platform_username("instagram", "nike").is_ok());
+
+    // A typo must not turn validation off
+    let error = validate_platform_username("instgram", "nike").unwrap_err();
+    assert_eq!(error.violations, [Violation::UnknownPlatform("instgram".to_string())]);
+    assert_eq!(error.to_string(), "no bundled platform named \"instgram\"");
+}
+
+#[test]
+fn test_bad_pattern_is_rejected() {
+    let definitions = |pattern: &str| format!(r#"
+        [[platforms]]
+        name = "typo"
+        url_template = "https://example.com/{{}}"
+
+        [platforms.validation]
+        pattern = '{}'
+    "#, pattern);
+
+    let error = parse_platforms(&definitions("^[a-z]+(")).unwrap_err();
+    assert!(format!("{:#}", error).contains("invalid pattern"), "{:#}", error);
+
+    let platforms = parse_platforms(&definitions("^[a-z]+$")).unwrap();
+    let rules = platforms[0].validation.as_ref().unwrap();
+    assert_eq!(rules.pattern.as_ref().map(Pattern::as_str), Some("^[a-z]+$"));
+    assert!(validate_username("chexx0r", rules).is_err());
+}
+
+// Social handle lookups: (platform, lookup index, username, status, body, expected verdict, expected label)
+type HandleCase = (&'static str, usize, &'static str, u16, &'static str, Verdict, Option<&'static str>);
+
+const SOCIAL_HANDLE_CASES: &[HandleCase] = &[
+    ("x", 0, "jack", 200, r#"{"screen_name":"jack"}"#, Verdict::Taken, None),
+    ("x", 0, "ghost", 200, "<span>This account doesn’t exist</span>", Verdict::Available, None),
+    ("x", 0, "spammer", 200, "<span>Account suspended</span>", Verdict::Suspended, None),
+    ("x", 0, "ghost", 200, "<div id=\"react-root\"></div>", Verdict::Next, None),
+    ("x", 1, "ghost", 404, "", Verdict::Available, None),
+    ("x", 1, "jack", 200, "", Verdict::Taken, None),
+    ("threads", 0, "zuck", 200, r#"<meta property="og:title" content="Mark Zuckerberg (@zuck) on Threads">"#, Verdict::Taken, None),
+    ("threads", 0, "ghost", 200, "<title>Threads</title>", Verdict::Available, None),
+    ("threads", 0, "ghost", 404, "", Verdict::Available, None),
+    ("bluesky", 0, "jay", 200, r#"{"did":"did:plc:oky5czdrnfjpqslsw2a5iclo"}"#, Verdict::Taken, None),
+    ("bluesky", 0, "ghost", 400, r#"{"error":"InvalidRequest","message":"Unable to resolve handle"}"#, Verdict::Available, None),
+    ("bluesky", 0, "ghost", 429, "", Verdict::RateLimited, None),
+    ("mastodon", 0, "gargron", 200, r#"{"subject":"acct:Gargron@mastodon.social"}"#, Verdict::Taken, None),
+    ("mastodon", 0, "ghost", 404, "", Verdict::Available, None),
+];
+
+#[test]
+fn test_social_handle_lookups() {
+    let mut failures = Vec::new();
+
+    for (platform, index, username, status, body, verdict, label) in SOCIAL_HANDLE_CASES {
+        let def = find_bundled(platform);
+        let probe = std::iter::once(&def.probe).chain(&def.fallbacks).nth(*index).unwrap();
+        let detection = match status_detection(probe, *status) {
+            d if d.verdict == Verdict::Inspect => evaluate_body(probe, username, body),
+            d => d,
+        };
+        if detection.verdict != *verdict || detection.label.as_deref() != *label {
+            failures.push(format!("✗ {}[{}]-{}: expected {:?}/{:?}, got {:?}", platform, index, username, verdict, label, detection));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} lookup mismatches:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+// Social handles: (username, platforms where it must be invalid)
+const SOCIAL_HANDLES: &[(&str, &[&str])] = &[
+    ("chexx0r", &[]),
+    ("abc", &["X"]),                                            // X needs 5+ chars
+    ("ab", &["X", "Bluesky"]),                                  // Bluesky needs 3+ chars
+    ("chexx0r_official", &["X", "Bluesky"]),                    // 16 chars > 15; no underscores on Bluesky
+    ("notadmin1", &["X"]),                                      // X forbids "admin"
+    ("MyTwitterFan", &["X"]),                                   // ...and "twitter" in any case
+    ("chex-x0r", &["X", "Threads", "Mastodon"]),                // Hyphens only on Bluesky
+    ("chex.x0r", &["X", "Bluesky", "Mastodon"]),                // Periods only on Threads
+    ("-chexx0r", &["X", "Threads", "Bluesky", "Mastodon"]),
+    ("ñandú", &["X", "Threads", "Bluesky", "Mastodon"]),        // ASCII only
+];
+
+#[test]
+fn test_social_handle_usernames() {
+    let validators: &[(&str, Validator)] = &[
+        ("X", validate_x_username),
+        ("Threads", validate_threads_username),
+        ("Bluesky", validate_bluesky_username),
+        ("Mastodon", validate_mastodon_username),
+    ];
+    let mut failures = Vec::new();
+
+    for (username, invalid_platforms) in SOCIAL_HANDLES {
+        for (platform, validate) in validators {
+            let should_be_invalid = invalid_platforms.contains(platform);
+            if validate(username).is_ok() == should_be_invalid {
+                failures.push(format!("✗ {}-{}: expected {}", platform, username, if should_be_invalid { "INVALID" } else { "VALID" }));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} validation failures:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+#[test]
+fn test_instance_expansion() {
+    let bundled_instances = chexx0r::config::expand_instances(vec![find_bundled("mastodon")], &[]);
+    let social = bundled_instances.iter().find(|p| p.name == "mastodon:mastodon.social").unwrap();
+    assert_eq!(social.probe.url_template, "https://mastodon.social/.well-known/webfinger?resource=acct:{}@mastodon.social");
+
+    let custom = chexx0r::config::expand_instances(vec![find_bundled("mastodon"), find_bundled("x")], &["fosstodon.org".to_string()]);
+    let names: Vec<&str> = custom.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["mastodon:fosstodon.org", "x"]);
+}
+
+// Messaging handles: (username, platforms where it must be invalid)
+const MESSAGING_HANDLES: &[(&str, &[&str])] = &[
+    ("chexx0r", &[]),
+    ("chex", &["Telegram"]),                                    // Telegram needs 5+ chars
+    ("ab", &["Telegram", "Snapchat", "Pinterest", "Reddit", "Subreddit"]), // Everyone needs 3+
+    ("0chexx0r", &["Telegram", "Snapchat"]),                    // Must start with a letter
+    ("chexx0r_", &["Telegram", "Snapchat"]),                    // Trailing underscore
+    ("chex__x0r", &["Telegram"]),                               // Consecutive underscores
+    ("_chexx0r", &["Telegram", "Snapchat", "Subreddit"]),
+    ("chex-x0r", &["Telegram", "Pinterest", "Subreddit"]),      // Hyphens on Snapchat and Reddit users
+    ("chexx0r_official_br", &["Snapchat"]),                     // 19 chars > 15
+    ("chexx0r_official_brand", &["Snapchat", "Reddit", "Subreddit"]), // 22 chars > 21
+];
+
+#[test]
+fn test_messaging_usernames() {
+    let validators: &[(&str, Validator)] = &[
+        ("Telegram", validate_telegram_username),
+        ("Snapchat", validate_snapchat_username),
+        ("Pinterest", validate_pinterest_username),
+        ("Reddit", validate_reddit_username),
+        ("Subreddit", validate_subreddit_name),
+    ];
+    let mut failures = Vec::new();
+
+    for (username, invalid_platforms) in MESSAGING_HANDLES {
+        for (platform, validate) in validators {
+            let should_be_invalid = invalid_platforms.contains(platform);
+            if validate(username).is_ok() == should_be_invalid {
+                failures.push(format!("✗ {}-{}: expected {}", platform, username, if should_be_invalid { "INVALID" } else { "VALID" }));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} validation failures:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+// Messaging lookups: (platform, username, status, body, expected verdict, expected label)
+type MessagingCase = (&'static str, &'static str, u16, &'static str, Verdict, Option<&'static str>);
+
+const MESSAGING_CASES: &[MessagingCase] = &[
+    ("telegram", "durov", 200, r#"<div class="tgme_page_title"><span dir="auto">Pavel Durov</span></div>"#, Verdict::Taken, None),
+    ("telegram", "ghost", 200, r#"<div class="tgme_page_description">If you have Telegram, you can contact</div>"#, Verdict::Available, None),
+    ("snapchat", "ghost", 404, "", Verdict::Available, None),
+    ("pinterest", "nasa", 200, r#"{"username":"nasa","full_name":"NASA"}"#, Verdict::Taken, None),
+    ("reddit", "spez", 200, r#"{"kind":"t2","data":{"name":"spez"}}"#, Verdict::Taken, None),
+    ("reddit", "spammer", 200, r#"{"kind":"t2","data":{"name":"spammer","is_suspended":true}}"#, Verdict::Suspended, None),
+    ("reddit", "ghost", 404, "", Verdict::Available, None),
+    ("subreddit", "rust", 200, r#"{"kind":"t5","data":{"display_name":"rust"}}"#, Verdict::Taken, None),
+    ("subreddit", "ghost", 200, r#"{"kind":"Listing","data":{"children":[]}}"#, Verdict::Available, None),
+    ("subreddit", "secret", 403, r#"{"reason":"private","message":"Forbidden"}"#, Verdict::Taken, Some("private")),
+    ("subreddit", "gone", 404, r#"{"reason":"banned","message":"Not Found"}"#, Verdict::Suspended, Some("banned")),
+    ("subreddit", "ghost", 404, r#"{"message":"Not Found","error":404}"#, Verdict::Available, None),
+];
+
+#[test]
+fn test_messaging_lookups() {
+    let mut failures = Vec::new();
+
+    for (platform, username, status, body, verdict, label) in MESSAGING_CASES {
+        let probe = find_bundled(platform).probe;
+        let detection = match status_detection(&probe, *status) {
+            d if d.verdict == Verdict::Inspect => evaluate_body(&probe, username, body),
+            d => d,
+        };
+        if detection.verdict != *verdict || detection.label.as_deref() != *label {
+            failures.push(format!("✗ {}-{}: expected {:?}/{:?}, got {:?}", platform, username, verdict, label, detection));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} lookup mismatches:\n{}", failures.len(), failures.join("\n"));
+    }
+}
@@ -0,0 +1,71 @@
+use chexx0r::*;
+use chexx0r::registry::normalize_name;
+use chexx0r::social::fill_url_template;
+
+// Normalization cases: (normalizer, name, expected)
+const NORMALIZATION_CASES: &[(&str, &str, &str)] = &[
+    ("pep503", "My_Lib", "my-lib"),
+    ("pep503", "my.lib", "my-lib"),
+    ("pep503", "My-._Lib", "my-lib"),   // Runs of separators collapse
+    ("npm", "MyLib", "mylib"),
+    ("npm", "@Acme/Tools", "@acme/tools"),
+    ("crates", "serde_json", "serde-json"),
+    ("crates", "Serde-JSON", "serde-json"),
+    ("go", "BurntSushi", "!burnt!sushi"), // Module proxy case escaping
+    ("none", "As_Is", "As_Is"),
+];
+
+#[test]
+fn test_registry_normalization() {
+    let mut failures = Vec::new();
+
+    for (normalizer, name, expected) in NORMALIZATION_CASES {
+        let normalized = normalize_name(normalizer, name);
+        if normalized != *expected {
+            failures.push(format!("✗ {}-{}: expected {}, got {}", normalizer, name, expected, normalized));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} normalization failures:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+// Registry names after normalization: (registry, normalized name, should be valid)
+const REGISTRY_NAMES: &[(&str, &str, bool)] = &[
+    ("crates.io", "serde-json", true),
+    ("crates.io", "1password", false),   // Must start with a letter
+    ("npm", "@acme/tools", true),
+    ("npm", "_private", false),          // Can't start with _
+    ("pypi", "my-lib", true),
+    ("pypi", "-my-lib", false),          // Must start with a letter or digit
+    ("rubygems", "rails", true),
+    ("rubygems", "1234", false),         // Must contain a letter
+    ("hex", "phoenix_live_view", true),
+    ("hex", "phoenix-live-view", false), // Hyphens not allowed
+];
+
+#[test]
+fn test_registry_validation() {
+    let platforms = bundled_platforms();
+    let mut failures = Vec::new();
+
+    for (registry, name, valid) in REGISTRY_NAMES {
+        let platform = platforms.iter().find(|p| p.name == *registry).unwrap();
+        assert_eq!(platform.group, "registries");
+        let rules = platform.validation.as_ref().unwrap();
+        if validate_username(name, rules).is_ok() != *valid {
+            failures.push(format!("✗ {}-{}: expected {}", registry, name, if *valid { "VALID" } else { "INVALID" }));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} validation failures:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+#[test]
+fn test_scoped_names_are_escaped_in_urls() {
+    assert_eq!(fill_url_template("https://registry.npmjs.org/{}", "@acme/tools"), "https://registry.npmjs.org/@acme%2Ftools");
+    assert_eq!(fill_url_template("https://proxy.golang.org/github.com/{}/{}/@v/list", "acme"), "https://proxy.golang.org/github.com/acme/acme/@v/list");
+}
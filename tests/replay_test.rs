@@ -0,0 +1,136 @@
+use chexx0r::*;
+use chexx0r::domain::check_domain_names;
+use chexx0r::social::check_social_media;
+
+const CASSETTES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/cassettes");
+
+// (username, platform, expected status, deciding rule)
+const REPLAY_CASES: &[(&str, &str, SocialStatus, &str)] = &[
+    ("mkbhd", "youtube", SocialStatus::Taken, "status 2xx"),
+    ("chexx0rghost", "youtube", SocialStatus::Available, "status 404"),
+    ("chexx0reu", "youtube", SocialStatus::Blocked, "wall redirect"),    // EU consent page, not a channel
+    ("mkbhd", "youtube:c", SocialStatus::Taken, "status 2xx"),
+    ("chexx0rghost", "youtube:user", SocialStatus::Available, "status 404"),
+    ("nike", "instagram", SocialStatus::Taken, "profile-json"),
+    ("chexx0rghost", "instagram", SocialStatus::Available, "status 404"),
+    ("chexx0rwalled", "instagram", SocialStatus::Available, "generic-title"),  // API wants a login, the page answers
+    ("chexx0rsplit", "instagram", SocialStatus::Unknown, "signals split"),     // Web and mobile APIs disagree
+    ("chexx0rghost", "tiktok", SocialStatus::Suspended, "banned"),
+    ("nike", "tiktok", SocialStatus::RateLimited, "status 429"),
+];
+
+fn replay_transport() -> impl Transport {
+    // Replay mode never reaches the wrapped transport
+    Cassette::new(CASSETTES, CassetteMode::Replay).wrap(ReqwestTransport::new().unwrap())
+}
+
+fn platforms(names: &[&str]) -> Vec<SocialPlatform> {
+    bundled_platforms().into_iter().filter(|p| names.contains(&p.name.as_str())).collect()
+}
+
+#[tokio::test]
+async fn test_replayed_platform_checks() {
+    let transport = replay_transport();
+    let mut failures = Vec::new();
+
+    for (username, platform, expected, rule) in REPLAY_CASES {
+        let results = check_social_media(username, &platforms(&[platform]), &transport, CheckOptions::default()).await.unwrap();
+        let result = &results[0];
+        if result.status != *expected || result.rule != *rule {
+            failures.push(format!(
+                "{} on {}: expected {:?} by {}, got {:?} by {}",
+                username, platform, expected, rule, result.status, result.rule
+            ));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Replayed checks failed:\n{}", failures.join("\n"));
+    }
+}
+
+#[tokio::test]
+async fn test_replayed_signals() {
+    let transport = replay_transport();
+    let signals = |result: &SocialResult| -> Vec<(String, Verdict)> {
+        result.signals.iter().map(|vote| (vote.signal.clone(), vote.verdict)).collect()
+    };
+
+    // Both APIs find the profile
+    let nike = &check_social_media("nike", &platforms(&["instagram"]), &transport, CheckOptions::default()).await.unwrap()[0];
+    assert_eq!((nike.status, nike.confidence), (SocialStatus::Taken, Confidence::High));
+    assert_eq!(signals(nike), [("api".to_string(), Verdict::Taken), ("mobile".to_string(), Verdict::Taken)]);
+
+    // The profile page decides, oEmbed's dissent costs a step of confidence
+    let banned = &check_social_media("chexx0rghost", &platforms(&["tiktok"]), &transport, CheckOptions::default()).await.unwrap()[0];
+    assert_eq!((banned.status, banned.confidence), (SocialStatus::Suspended, Confidence::Medium));
+    assert!(banned.signals[1].disagrees_with(banned.status));
+}
+
+#[tokio::test]
+async fn test_missing_recording_is_unknown() {
+    let transport = replay_transport();
+    let results = check_social_media("neverrecorded", &platforms(&["youtube"]), &transport, CheckOptions::default()).await.unwrap();
+
+    assert_eq!(results[0].status, SocialStatus::Unknown);
+    assert_eq!(results[0].rule, "error");
+}
+
+#[tokio::test]
+async fn test_invalid_names_say_why() {
+    // Nothing is requested for a name the rules refuse
+    let transport = replay_transport();
+    let results = check_social_media("-x", &platforms(&["youtube"]), &transport, CheckOptions::default()).await.unwrap();
+
+    assert_eq!(results[0].status, SocialStatus::Invalid);
+    assert_eq!(results[0].detail.as_deref(), Some("too short (2 < 3), can't start with '-'"));
+}
+
+#[tokio::test]
+async fn test_replayed_domain_checks() {
+    let cassette = Cassette::new(CASSETTES, CassetteMode::Replay);
+    let domains = ["chexx0r.com", "chexx0r.dev", "chexx0r.io"].map(String::from);
+    let options = DomainOptions { cassette: Some(&cassette), ..DomainOptions::default() };
+    let results = check_domain_names(&domains, &replay_transport(), &options).await.unwrap();
+
+    let answers: Vec<(Option<bool>, Confidence, &str)> = results.iter()
+        .map(|r| (r.available, r.confidence, r.rule.as_str()))
+        .collect();
+    assert_eq!(answers, [
+        (Some(false), Confidence::High, "rdap"),
+        (Some(true), Confidence::Medium, "whois"),
+        (None, Confidence::Low, "not recorded"),
+    ]);
+}
+
+#[test]
+fn test_recorded_response_replays() {
+    let dir = std::env::temp_dir().join(format!("chexx0r-cassette-{}", std::process::id()));
+    let request = HttpRequest {
+        method: "POST".to_string(),
+        url: "https://gql.example/graphql".to_string(),
+        headers: vec![("Authorization".to_string(), "Bearer secret".to_string())],
+        body: Some(r#"{"login":"ghost"}"#.to_string()),
+        ..HttpRequest::default()
+    };
+    let response = HttpResponse {
+        status: 200,
+        headers: vec![("Set-Cookie".to_string(), "sessionid=live-session; Path=/; Secure".to_string())],
+        body: r#"{"user":null}"#.to_string(),
+        ..HttpResponse::default()
+    };
+
+    Cassette::new(&dir, CassetteMode::Record).record(&request, &response).unwrap();
+    let cassette = Cassette::new(&dir, CassetteMode::Replay);
+    let replayed = cassette.replay(&request).unwrap();
+    let saved = std::fs::read_to_string(cassette.request_path(&request)).unwrap();
+    let other_body = HttpRequest { body: Some(r#"{"login":"other"}"#.to_string()), ..request.clone() };
+    let missing = cassette.replay(&other_body);
+    std::fs::remove_dir_all(&dir).ok();
+
+    assert_eq!((replayed.status, replayed.body.as_str()), (200, r#"{"user":null}"#));
+    assert!(!saved.contains("secret"), "request headers are never written: {}", saved);
+    assert!(!saved.contains("live-session"), "cookie values are scrubbed: {}", saved);
+    assert_eq!(replayed.headers, [("Set-Cookie".to_string(), "sessionid=scrubbed; Path=/; Secure".to_string())]);
+    assert!(missing.is_err(), "a different body is a different recording");
+}
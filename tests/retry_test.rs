@@ -0,0 +1,89 @@
+mod common;
+
+use chexx0r::*;
+use chexx0r::retry::{backoff_delay, parse_retry_after, should_retry};
+use chexx0r::social::check_social_media;
+use common::{find_bundled, header, response, FakeTransport};
+use std::time::{Duration, SystemTime};
+
+// (Retry-After value, expected delay in seconds)
+const RETRY_AFTER_CASES: &[(&str, Option<u64>)] = &[
+    ("120", Some(120)),
+    (" 0 ", Some(0)),
+    ("Thu, 01 Jan 1970 00:02:00 GMT", Some(60)),
+    ("Thu, 01 Jan 1970 00:00:30 GMT", Some(0)),
+    ("soon", None),
+    ("-5", None),
+];
+
+#[test]
+fn test_retry_after_parsing() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(60);
+    let mut failures = Vec::new();
+
+    for (value, expected) in RETRY_AFTER_CASES {
+        let parsed = parse_retry_after(value, now).map(|d| d.as_secs());
+        if parsed != *expected {
+            failures.push(format!("{:?}: expected {:?}, got {:?}", value, expected, parsed));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Retry-After parsing failed:\n{}", failures.join("\n"));
+    }
+}
+
+#[test]
+fn test_backoff_doubles_within_jitter_and_cap() {
+    let policy = RetryPolicy { base_delay_ms: 100, max_delay_ms: 350, ..RetryPolicy::default() };
+
+    // (retry, unjittered delay)
+    for (retry, full) in [(1, 100), (2, 200), (3, 350), (10, 350)] {
+        for _ in 0..50 {
+            let delay = backoff_delay(&policy, retry).as_millis() as u64;
+            assert!((full / 2..=full).contains(&delay), "retry {}: {}ms outside {}..={}", retry, delay, full / 2, full);
+        }
+    }
+    assert!(should_retry(&policy, 429) && should_retry(&policy, 503));
+    assert!(!should_retry(&policy, 404) && !should_retry(&policy, 200));
+}
+
+type RetryCase = (&'static [(u16, Option<&'static str>)], SocialStatus, u32);
+
+// (responses in order, expected status, expected retries); 3 attempts allowed
+const RETRY_CASES: &[RetryCase] = &[
+    (&[(200, None)], SocialStatus::Taken, 0),
+    (&[(503, None), (200, None)], SocialStatus::Taken, 1),
+    (&[(429, Some("0")), (503, None), (404, None)], SocialStatus::Available, 2),
+    (&[(503, None)], SocialStatus::Unknown, 2),
+    (&[(429, Some("3600")), (200, None)], SocialStatus::RateLimited, 0),
+];
+
+#[tokio::test]
+async fn test_platform_retries_are_counted() {
+    let mut youtube = find_bundled("youtube");
+    youtube.probe.status.insert("429".to_string(), Verdict::RateLimited);
+    youtube.retry = RetryPolicy { max_attempts: 3, base_delay_ms: 1, max_delay_ms: 5, ..RetryPolicy::default() };
+    let platforms = [youtube];
+    let mut failures = Vec::new();
+
+    for (script, expected, retries) in RETRY_CASES {
+        let transport = FakeTransport::scripted(script.iter()
+            .map(|(status, retry_after)| HttpResponse {
+                headers: retry_after.iter().map(|v| header("Retry-After", v)).collect(),
+                ..response(*status, "")
+            })
+            .collect());
+        let results = check_social_media("someone", &platforms, &transport, CheckOptions::default()).await.unwrap();
+        if results[0].status != *expected || results[0].retries != *retries {
+            failures.push(format!(
+                "{:?}: expected {:?} after {} retries, got {:?} after {}",
+                script, expected, retries, results[0].status, results[0].retries
+            ));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Retries failed:\n{}", failures.join("\n"));
+    }
+}
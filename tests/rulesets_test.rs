@@ -0,0 +1,172 @@
+use chexx0r::*;
+
+// Username corpus: (platform, names its rules accept, names they refuse)
+// Every bundled ruleset has a row; a rule change shows up here first
+type CorpusRow = (&'static str, &'static [&'static str], &'static [&'static str]);
+
+const CORPUS: &[CorpusRow] = &[
+    // social
+    ("youtube", &["mkbhd", "chex.x0r", "chex-x0r.tv", "ñandú", "abc", "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"],
+        &["ab", "-chexx0r", "chexx0r_", "chex x0r", "user🚀name", "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"]),
+    ("youtube:c", &["mkbhd", "MKBHD"], &["chex_x0r", "chex.x0r", "ñandú"]),
+    ("youtube:user", &["pewdiepie"], &["chex_x0r", "aaaaaaaaaaaaaaaaaaaaa", "ñandú"]),
+    ("instagram", &["nike", "chex.x0r", "_chexx0r_", "a", "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"],
+        &[".chexx0r", "chexx0r.", "chex..x0r", "chex-x0r", "ñandú", "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"]),
+    ("threads", &["zuck", "chex.x0r"], &["chexx0r.", "chex..x0r", "ñandú"]),
+    ("tiktok", &["charlidamelio", "chex.x0r", "chex_x0r", "a"],
+        &["1chexx0r", "_chexx0r", "chexx0r.", "chex__x0r", "chex-x0r", "ñandú", "aaaaaaaaaaaaaaaaaaaaaaaaa"]),
+    ("x", &["elonmusk", "chex_x0r"], &["abcd", "chexx0r_official", "notadmin1", "mytwitterfan", "chex.x0r", "ñandú"]),
+    ("bluesky", &["jay", "chex-x0r"], &["ab", "-chexx0r", "chexx0r-", "chex_x0r", "ñandú"]),
+    ("mastodon", &["gargron", "chex_x0r"], &["chex-x0r", "chex.x0r", "ñandú", "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"]),
+    ("telegram", &["durov", "chex_x0r"], &["abcd", "1chexx0r", "chexx0r_", "chex__x0r", "ñandú"]),
+    ("snapchat", &["chexx0r", "chex.x-0r"], &["ab", "1chexx0r", "chexx0r.", "ñandú"]),
+    ("pinterest", &["chexx0r", "chex_x0r"], &["ab", "chex.x0r", "ñandú"]),
+    ("reddit", &["spez", "chex-x0r", "chex_x0r"], &["ab", "chex.x0r", "ñandú", "aaaaaaaaaaaaaaaaaaaaa"]),
+    ("subreddit", &["rust", "rust_gamedev"], &["ab", "_rust", "rust-lang", "ñandú"]),
+    // code
+    ("github", &["rust-lang", "a"], &["-rust", "rust-", "rust--lang", "rust_lang", "ñandú"]),
+    ("gitlab", &["gitlab-org", "rust.lang", "_rust"], &["a", "-rust", "rust.", "rust.git", "rust.atom", "ñandú"]),
+    ("codeberg", &["forgejo", "rust-lang", "rust.lang"], &["-rust", "rust-", "rust--lang", "ñandú"]),
+    ("bitbucket", &["atlassian", "rust_lang"], &["RustLang", "rust.lang", "ñandú"]),
+    // creators
+    ("substack", &["chexx0r", "chex-x0r"], &["-chexx0r", "chexx0r-", "chex_x0r", "ñandú"]),
+    ("bandcamp", &["chexx0r"], &["chex_x0r", "ñandú"]),
+    ("tumblr", &["chexx0r"], &["chexx0r-", "ñandú"]),
+    ("ko-fi", &["chexx0r", "chex_x0r"], &["ab", "chex-x0r", "ñandú"]),
+    ("patreon", &["chexx0r"], &["chex.x0r", "ñandú"]),
+    ("medium", &["chexx0r", "chex.x0r"], &["chex-x0r", "ñandú"]),
+    ("linktree", &["chexx0r", "chex.x0r"], &["ab", "chex-x0r", "ñandú"]),
+    // gaming
+    ("twitch", &["chexx0r", "chex_x0r"], &["abc", "_chexx0r", "chex-x0r", "ñandú"]),
+    ("kick", &["chexx0r"], &["abc", "_chexx0r", "ñandú"]),
+    ("steam", &["chexx0r", "chex-x0r_"], &["ab", "chex.x0r", "ñandú"]),
+    ("roblox", &["chexx0r", "chex_x0r"], &["ab", "_chexx0r", "chex_x_0r", "ñandú"]),
+    // registries
+    ("crates.io", &["serde", "serde_json", "tokio-util"], &["1serde", "serde.json", "ñandú"]),
+    ("npm", &["react", "@types/node", "lodash.merge"], &["React", ".hidden", "_private", "ñandú"]),
+    ("pypi", &["requests", "zope.interface", "a"], &["-requests", "requests_", "ñandú"]),
+    ("rubygems", &["rails", "active_support"], &["1234", "ñandú"]),
+    ("go", &["chexx0r", "chex.x0r"], &["chex x0r", "ñandú"]),
+    ("hex", &["phoenix", "ecto_sql"], &["1phoenix", "Phoenix", "ecto-sql", "ñandú"]),
+    // channels
+    ("docker-hub", &["chexx0r"], &["abc", "chex-x0r", "ñandú"]),
+    ("docker-official", &["ubuntu", "node-red"], &["-ubuntu", "ubuntu--", "ñandú"]),
+    ("homebrew", &["wget", "python@3.12"], &["-wget", "ñandú"]),
+    ("homebrew-cask", &["firefox", "firefox@beta"], &["firefox-", "fire--fox", "ñandú"]),
+    ("flathub", &["org.mozilla.firefox", "io.github.chexx0r.chex-x0r"], &["firefox", "org.mozilla", "1org.mozilla.firefox", "ñandú.a.b"]),
+    ("snapcraft", &["firefox", "vlc-2"], &["a", "1234", "-vlc", "ñandú"]),
+    ("aur", &["yay", "python-requests"], &["-yay", ".yay", "ñandú"]),
+];
+
+#[test]
+fn test_username_corpus() {
+    let platforms = bundled_platforms();
+    let mut failures = Vec::new();
+
+    for (name, valid, invalid) in CORPUS {
+        let Some(rules) = platforms.iter().find(|p| p.name == *name).and_then(|p| p.validation.as_ref()) else {
+            failures.push(format!("✗ {}: no bundled ruleset", name));
+            continue;
+        };
+        let expectations = valid.iter().map(|u| (u, true)).chain(invalid.iter().map(|u| (u, false)));
+        for (username, should_be_valid) in expectations {
+            match validate_username(username, rules) {
+                Ok(()) if !should_be_valid => failures.push(format!("✗ {}-{}: expected INVALID", name, username)),
+                Err(error) if should_be_valid => failures.push(format!("✗ {}-{}: expected VALID ({})", name, username, error)),
+                _ => {}
+            }
+        }
+    }
+
+    for platform in platforms.iter().filter(|p| p.validation.is_some()) {
+        if !CORPUS.iter().any(|(name, _, _)| *name == platform.name) {
+            failures.push(format!("✗ {}: ruleset has no corpus row", platform.name));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} corpus failures:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+/// YYYY-MM-DD
+fn is_date(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+    matches!(parts.as_slice(), [y, m, d] if y.len() == 4 && m.len() == 2 && d.len() == 2)
+        && parts.iter().all(|part| part.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// An https page, not a bare site (a homepage states no rules)
+fn is_specific_page(url: &str) -> bool {
+    reqwest::Url::parse(url).is_ok_and(|url| {
+        url.scheme() == "https" && (!url.path().trim_matches('/').is_empty() || url.fragment().is_some())
+    })
+}
+
+// Provenance backlog: every ruleset needs the page that states its rules and the date it was last
+// checked against it. Rulesets still missing either are listed here until someone adds them;
+// adding one means removing the name, so the lists only ever shrink
+const AWAITING_SOURCE: &[&str] = &[
+    "youtube:c", "youtube:user", "instagram", "threads", "bluesky", "snapchat", "pinterest",
+    "github", "bitbucket",
+    "substack", "bandcamp", "tumblr", "ko-fi", "patreon", "medium", "linktree",
+    "twitch", "kick", "steam", "roblox",
+    "rubygems", "hex",
+];
+
+const AWAITING_VERIFICATION: &[&str] = &[
+    "youtube", "youtube:c", "youtube:user", "instagram", "tiktok", "x", "threads", "bluesky", "mastodon",
+    "telegram", "snapchat", "pinterest", "reddit", "subreddit",
+    "github", "gitlab", "codeberg", "bitbucket",
+    "substack", "bandcamp", "tumblr", "ko-fi", "patreon", "medium", "linktree",
+    "twitch", "kick", "steam", "roblox",
+    "crates.io", "npm", "pypi", "rubygems", "go", "hex",
+    "docker-hub", "docker-official", "homebrew", "homebrew-cask", "flathub", "snapcraft", "aur",
+];
+
+#[test]
+fn test_ruleset_provenance() {
+    let platforms = bundled_platforms();
+    let mut failures = Vec::new();
+
+    for platform in &platforms {
+        let Some(rules) = &platform.validation else { continue };
+        let name = platform.name.as_str();
+        if rules.version == 0 {
+            failures.push(format!("✗ {}: version starts at 1", name));
+        }
+
+        match (rules.source.as_deref(), AWAITING_SOURCE.contains(&name)) {
+            (None, false) => failures.push(format!("✗ {}: no source page for its rules", name)),
+            (Some(_), true) => failures.push(format!("✗ {}: has a source now, take it off AWAITING_SOURCE", name)),
+            (Some(source), false) if !is_specific_page(source) => {
+                failures.push(format!("✗ {}: source should be the https page stating the rules, got {}", name, source));
+            }
+            _ => {}
+        }
+
+        match (rules.verified.as_deref(), AWAITING_VERIFICATION.contains(&name)) {
+            (None, false) => failures.push(format!("✗ {}: no verified date", name)),
+            (Some(_), true) => failures.push(format!("✗ {}: verified now, take it off AWAITING_VERIFICATION", name)),
+            (Some(_), false) if rules.source.is_none() => failures.push(format!("✗ {}: verified against no source", name)),
+            (Some(date), false) if !is_date(date) => {
+                failures.push(format!("✗ {}: verified should be YYYY-MM-DD, got {}", name, date));
+            }
+            _ => {}
+        }
+    }
+
+    for name in AWAITING_SOURCE.iter().chain(AWAITING_VERIFICATION) {
+        if !platforms.iter().any(|p| p.name == *name && p.validation.is_some()) {
+            failures.push(format!("✗ {}: listed in the provenance backlog but has no ruleset", name));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} ruleset provenance problems:\n{}", failures.len(), failures.join("\n"));
+    }
+
+    let homepage = "https://help.instagram.com/";
+    assert!(!is_specific_page(homepage), "a homepage isn't a source");
+    assert!(is_specific_page("https://telegram.org/faq#q-what-can-i-use-as-my-username"));
+}
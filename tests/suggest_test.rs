@@ -0,0 +1,85 @@
+use chexx0r::*;
+
+// Suggestion cases: (username, markets, already-checked TLDs, domains that must be suggested)
+type SuggestionCase = (&'static str, &'static [&'static str], &'static [&'static str], &'static [&'static str]);
+
+const SUGGESTION_CASES: &[SuggestionCase] = &[
+    ("chexly", &[], &[], &["chex.ly"]),             // Domain hack: name ends with "ly"
+    ("code-sh", &[], &[], &["code.sh"]),            // Domain hack: trailing hyphen is dropped from the stem
+    ("CoolShop", &[], &[], &["coolshop.store", "coolshop.shop"]), // Industry keyword as a camelCase word
+    ("cool-shop", &[], &[], &["cool-shop.store"]),  // Industry keyword as a separated word
+    ("coolshop", &[], &[], &["coolshop.store", "coolshop.shop"]), // Industry keyword ending a compound
+    ("DevTools", &[], &[], &["devtools.dev", "devtools.sh"]),     // Dev-ish keyword
+    ("acme", &["uk", "de"], &[], &["acme.uk", "acme.co.uk", "acme.de"]), // Markets from the table
+    ("acme", &["se"], &[], &["acme.se"]),           // Market missing from the table is used as ccTLD
+];
+
+#[test]
+fn test_suggested_domains() {
+    let table = SuggestionTable::from_toml(chexx0r::config::BUNDLED_TLD_SUGGESTIONS).unwrap();
+    let mut failures = Vec::new();
+
+    for (username, markets, checked, expected) in SUGGESTION_CASES {
+        let markets: Vec<String> = markets.iter().map(|s| s.to_string()).collect();
+        let checked: Vec<String> = checked.iter().map(|s| s.to_string()).collect();
+        let suggested = suggest_domains(username, &table, &markets, &checked);
+
+        for domain in *expected {
+            if !suggested.iter().any(|d| d == domain) {
+                failures.push(format!("✗ {}: expected {} in {:?}", username, domain, suggested));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} missing suggestions:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+#[test]
+fn test_suggestions_skip_checked_tlds() {
+    let table = SuggestionTable::from_toml(chexx0r::config::BUNDLED_TLD_SUGGESTIONS).unwrap();
+    let checked = vec!["dev".to_string()];
+    let suggested = suggest_domains("DevTools", &table, &[], &checked);
+
+    assert!(!suggested.contains(&"devtools.dev".to_string()), "already-checked TLD suggested again: {:?}", suggested);
+    assert!(suggested.contains(&"devtools.sh".to_string()));
+}
+
+#[test]
+fn test_keywords_match_words_not_substrings() {
+    let table = SuggestionTable::from_toml(chexx0r::config::BUNDLED_TLD_SUGGESTIONS).unwrap();
+    // (username, TLD a raw substring would wrongly suggest)
+    let cases = [
+        ("chainmail", "ai"), ("startup", "art"), ("digital", "dev"), ("capital", "io"),
+        ("airline", "ai"), ("hostile", "host"), ("payload", "money"),
+    ];
+    let mut failures = Vec::new();
+
+    for (username, tld) in cases {
+        let suggested = suggest_domains(username, &table, &[], &[]);
+        if suggested.contains(&format!("{}.{}", username, tld)) {
+            failures.push(format!("✗ {}: unexpected .{} in {:?}", username, tld, suggested));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Found {} substring matches:\n{}", failures.len(), failures.join("\n"));
+    }
+}
+
+#[test]
+fn test_user_table_extends_bundled() {
+    let mut table = SuggestionTable::from_toml(chexx0r::config::BUNDLED_TLD_SUGGESTIONS).unwrap();
+    let extra = SuggestionTable::from_toml(r#"
+        [keywords]
+        shop = ["boutique"]
+        yoga = ["yoga"]
+    "#).unwrap();
+    table.merge(extra);
+
+    let suggested = suggest_domains("YogaShop", &table, &[], &[]);
+    for domain in ["yogashop.store", "yogashop.shop", "yogashop.boutique", "yogashop.yoga"] {
+        assert!(suggested.contains(&domain.to_string()), "missing {} in {:?}", domain, suggested);
+    }
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use chexx0r::*;
+use chexx0r::social::check_social_media;
+use chexx0r::throttle::throttle_key;
+use common::{find_bundled, response, FakeTransport};
+use std::time::{Duration, Instant};
+
+fn youtube(trip_after: u32) -> SocialPlatform {
+    let mut youtube = find_bundled("youtube");
+    youtube.probe.status.insert("429".to_string(), Verdict::RateLimited);
+    youtube.retry.max_attempts = 1;
+    youtube.rate_limit.trip_after = trip_after;
+    youtube
+}
+
+#[tokio::test]
+async fn test_breaker_skips_blocking_host() {
+    let platforms = [youtube(2)];
+    let transport = FakeTransport::scripted(vec![response(429, "")]);
+    let throttle = Throttle::new();
+    let options = CheckOptions { throttle: Some(&throttle), ..CheckOptions::default() };
+
+    let mut rules = Vec::new();
+    for name in ["first", "second", "third", "fourth"] {
+        let results = check_social_media(name, &platforms, &transport, options).await.unwrap();
+        assert_eq!(results[0].status, SocialStatus::RateLimited, "{}", name);
+        rules.push(results[0].rule.clone());
+    }
+
+    assert_eq!(rules, ["status 429", "status 429", "circuit open", "circuit open"]);
+    assert_eq!(transport.sent().len(), 2, "no requests once the breaker is open");
+}
+
+#[tokio::test]
+async fn test_real_answer_resets_breaker() {
+    let platforms = [youtube(2)];
+    let transport = FakeTransport::scripted(vec![response(429, "")]);
+    let throttle = Throttle::new();
+    let options = CheckOptions { throttle: Some(&throttle), ..CheckOptions::default() };
+
+    check_social_media("first", &platforms, &transport, options).await.unwrap();
+    transport.set_fallback(response(200, ""));
+    check_social_media("second", &platforms, &transport, options).await.unwrap();
+    transport.set_fallback(response(429, ""));
+    let results = check_social_media("third", &platforms, &transport, options).await.unwrap();
+
+    assert_eq!(results[0].rule, "status 429", "the 200 in between reset the count");
+    assert_eq!(throttle.tripped("www.youtube.com"), None);
+}
+
+#[tokio::test]
+async fn test_token_bucket_paces_requests() {
+    let throttle = Throttle::new();
+    let limit = RateLimit { per_second: Some(50.0), burst: 2, ..RateLimit::default() };
+
+    let start = Instant::now();
+    for _ in 0..6 {
+        throttle.acquire("mock", &limit).await;
+    }
+    let elapsed = start.elapsed();
+
+    // Two go out at once, the other four wait 20ms each
+    assert!(elapsed >= Duration::from_millis(75), "6 requests at 50/s with a burst of 2 took {:?}", elapsed);
+    assert!(elapsed < Duration::from_millis(500), "took {:?}", elapsed);
+}
+
+#[test]
+fn test_throttle_keys() {
+    let platforms = bundled_platforms();
+    let key = |name: &str| throttle_key(platforms.iter().find(|p| p.name == name).unwrap());
+
+    assert_eq!(key("youtube"), "www.youtube.com");
+    assert_eq!(key("instagram"), "meta");
+    assert_eq!(key("threads"), "meta", "Instagram and Threads share a bucket");
+    assert_eq!(key("substack"), "substack.com", "subdomain lookups count against the platform host");
+    assert_eq!(key("reddit"), key("subreddit"), "same host, same bucket");
+}
@@ -0,0 +1,150 @@
+mod common;
+
+use chexx0r::*;
+use chexx0r::config::rebase_url_template;
+use chexx0r::domain::check_domain_names;
+use chexx0r::social::check_social_media;
+use common::{find_bundled, response, FakeTransport};
+use std::collections::BTreeMap;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+// (template, base, expected)
+const REBASE_CASES: &[(&str, &str, &str)] = &[
+    ("https://www.youtube.com/@{}", "http://127.0.0.1:8080", "http://127.0.0.1:8080/@{}"),
+    ("https://api.github.com/users/{}", "http://localhost:9000/", "http://localhost:9000/users/{}"),
+    ("https://bsky.social/xrpc/resolve?handle={}", "http://mock", "http://mock/xrpc/resolve?handle={}"),
+    ("https://{}.substack.com/", "http://localhost:9000", "http://{}.localhost:9000/"),
+    ("https://t.me/{}", "http://mock", "http://mock/{}"),
+];
+
+#[test]
+fn test_base_url_rewrites_host_only() {
+    let mut failures = Vec::new();
+
+    for (template, base, expected) in REBASE_CASES {
+        let rebased = rebase_url_template(template, base);
+        if rebased != *expected {
+            failures.push(format!("{} on {}: expected {}, got {}", template, base, expected, rebased));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("Base URL rewrites failed:\n{}", failures.join("\n"));
+    }
+}
+
+#[test]
+fn test_base_url_covers_instances_and_fallbacks() {
+    let mut platforms = config::expand_instances(bundled_platforms(), &["fosstodon.org".to_string()]);
+    let base_urls = BTreeMap::from([
+        ("mastodon".to_string(), "http://127.0.0.1:1".to_string()),
+        ("x".to_string(), "http://127.0.0.1:2".to_string()),
+    ]);
+    apply_base_urls(&mut platforms, &base_urls);
+
+    for platform in platforms.iter().filter(|p| p.name == "mastodon:fosstodon.org" || p.name == "x") {
+        for probe in std::iter::once(&platform.probe).chain(&platform.fallbacks) {
+            assert!(probe.url_template.starts_with("http://127.0.0.1:"), "{} still points at {}", platform.name, probe.url_template);
+        }
+    }
+}
+
+/// Serve `/@taken` as a profile, `/old` as a redirect to it, everything else as 404
+async fn mock_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut buf = vec![0; 4096];
+            let n = socket.read(&mut buf).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let path = request.split_whitespace().nth(1).unwrap_or("/");
+            let response = match path {
+                "/@taken" => "HTTP/1.1 200 OK\r\ncontent-length: 7\r\nconnection: close\r\n\r\nprofile",
+                "/old" => "HTTP/1.1 301 Moved Permanently\r\nlocation: /@taken\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                _ => "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+            };
+            socket.write_all(response.as_bytes()).await.ok();
+        }
+    });
+
+    base
+}
+
+#[tokio::test]
+async fn test_checks_against_mock_server() {
+    let base = mock_server().await;
+    let mut platforms = vec![find_bundled("youtube")];
+    apply_base_urls(&mut platforms, &BTreeMap::from([("youtube".to_string(), base.clone())]));
+    let transport = ReqwestTransport::new().unwrap();
+
+    let taken = check_social_media("taken", &platforms, &transport, CheckOptions::default()).await.unwrap();
+    let free = check_social_media("freename", &platforms, &transport, CheckOptions::default()).await.unwrap();
+    assert_eq!(taken[0].status, SocialStatus::Taken);
+    assert_eq!(free[0].status, SocialStatus::Available);
+
+    let request = HttpRequest {
+        method: "GET".to_string(),
+        url: format!("{}/old", base),
+        follow_redirects: true,
+        ..HttpRequest::default()
+    };
+    let response = transport.send(&request).await.unwrap();
+    assert_eq!(response.status, 200);
+    assert_eq!(response.redirects, [format!("{}/@taken", base)]);
+}
+
+#[tokio::test]
+async fn test_caller_supplies_transport() {
+    let transport = FakeTransport::new(&[
+        ("https://www.instagram.com/api/v1/users/web_profile_info/?username=nike", response(401, "")),
+        ("https://www.instagram.com/nike", response(200, "<title>Nike (@nike) • Instagram photos and videos</title>")),
+        ("https://i.instagram.com/api/v1/users/web_profile_info/?username=nike", response(200, r#"{"data":{"user":{"id":"13460080"}}}"#)),
+    ], response(404, ""));
+    let platforms = vec![find_bundled("instagram")];
+
+    let results = check_social_media("nike", &platforms, &transport, CheckOptions::default()).await.unwrap();
+    assert_eq!(results[0].status, SocialStatus::Taken);
+    assert_eq!(transport.urls(), [
+        "https://www.instagram.com/api/v1/users/web_profile_info/?username=nike",
+        "https://www.instagram.com/nike",
+        "https://i.instagram.com/api/v1/users/web_profile_info/?username=nike",
+    ]);
+}
+
+#[tokio::test]
+async fn test_domain_lookups_use_transport() {
+    // The https server is preferred, then rebased like the bootstrap file itself
+    let bootstrap = r#"{"services": [
+        [["com", "net"], ["http://rdap.verisign.com/com/v1/", "https://rdap.verisign.com/com/v1/"]],
+        [["dev"], ["https://pubapi.registry.google/rdap"]]
+    ]}"#;
+    let transport = FakeTransport::new(&[
+        ("http://mock/rdap/dns.json", response(200, bootstrap)),
+        ("http://mock/com/v1/domain/chexx0r.com", response(200, r#"{"ldhName":"CHEXX0R.COM"}"#)),
+    ], response(404, ""));
+    let options = DomainOptions { rdap_base: Some("http://mock".to_string()), ..DomainOptions::default() };
+    let domains = ["chexx0r.com", "chexx0r.dev"].map(String::from);
+
+    let results = check_domain_names(&domains, &transport, &options).await.unwrap();
+    let answers: Vec<(Option<bool>, &str)> = results.iter().map(|r| (r.available, r.rule.as_str())).collect();
+    assert_eq!(answers, [(Some(false), "rdap"), (Some(true), "rdap")]);
+    assert_eq!(transport.urls(), [
+        "http://mock/rdap/dns.json",
+        "http://mock/com/v1/domain/chexx0r.com",
+        "http://mock/rdap/domain/chexx0r.dev",
+    ]);
+}
+
+#[tokio::test]
+async fn test_domain_lookups_keep_their_pin() {
+    let transport = FakeTransport::new(&[], response(404, ""));
+    let options = DomainOptions { proxy: Some("socks5://127.0.0.1:1080".to_string()), ..DomainOptions::default() };
+
+    // No bootstrap answer means no RDAP servers; the bootstrap request still carries the pin
+    check_domain_names(&[], &transport, &options).await.unwrap();
+    let pins: Vec<Option<String>> = transport.sent().into_iter().map(|r| r.proxy).collect();
+    assert_eq!(pins, [Some("socks5://127.0.0.1:1080".to_string())]);
+}